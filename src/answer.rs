@@ -0,0 +1,94 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Text(String),
+}
+
+impl Answer {
+    fn as_integer(&self) -> Option<i128> {
+        match self {
+            Self::Signed(n) => Some(*n as i128),
+            Self::Unsigned(n) => Some(*n as i128),
+            Self::Text(_) => None,
+        }
+    }
+}
+
+// Numbers compare by value regardless of signedness, anything involving
+// text compares by how it is displayed, so "280" == Unsigned(280).
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.as_integer(), other.as_integer()) {
+            (Some(a), Some(b)) => a == b,
+            _ => self.to_string() == other.to_string(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Signed(n) => write!(f, "{}", n),
+            Self::Unsigned(n) => write!(f, "{}", n),
+            Self::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident, $target:ty, $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Self::$variant(n as $target)
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(Signed, i64, i8, i16, i32, i64);
+impl_from!(Unsigned, u64, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::Text(s.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_numerically() {
+        assert_eq!(Answer::Signed(58), Answer::Unsigned(58));
+        assert_ne!(Answer::Signed(-1), Answer::Unsigned(u64::MAX));
+        assert_eq!(Answer::from("58"), Answer::from(58u32));
+        assert_ne!(Answer::from("058"), Answer::from(58u32));
+    }
+
+    #[test]
+    fn serializes_typed_json() {
+        assert_eq!(serde_json::to_string(&Answer::from(-3)).unwrap(), "-3");
+        assert_eq!(serde_json::to_string(&Answer::from(3usize)).unwrap(), "3");
+        assert_eq!(
+            serde_json::to_string(&Answer::from("abc")).unwrap(),
+            "\"abc\""
+        );
+    }
+}
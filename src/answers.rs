@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::answer::Answer;
+use crate::days::{self, Day, Part, PARTS};
+use crate::error::AocError;
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+// Known good answers for real puzzle inputs, keyed by day and part:
+//
+//     [day01]
+//     part1 = 280
+//     part2 = 1797
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: HashMap<(Day, Part), Answer>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        let data = crate::load_data(path.to_path_buf())?;
+        Self::parse(&data)
+    }
+
+    pub fn parse(data: &str) -> Result<Self, AocError> {
+        let table: toml::Table = data.parse().map_err(|e: toml::de::Error| {
+            let rest = e.span().map_or("", |span| &data[span.start..]);
+            AocError::parse(data, rest, e.message().trim())
+        })?;
+
+        let mut answers = HashMap::new();
+        for (day_key, parts) in table.iter() {
+            let invalid = |message: String| {
+                let rest = data.find(day_key.as_str()).map_or("", |i| &data[i..]);
+                AocError::parse(data, rest, message)
+            };
+
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|d| d.parse::<Day>().ok())
+                .ok_or_else(|| invalid(format!("unexpected answers section {}", day_key)))?;
+
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| invalid(format!("answers for {} should be a table", day_key)))?;
+
+            for part in PARTS {
+                let answer = match parts.get(&format!("part{}", part)) {
+                    None => continue,
+                    Some(toml::Value::String(s)) => Answer::from(s.as_str()),
+                    Some(toml::Value::Integer(n)) => Answer::from(*n),
+                    Some(value) => Answer::from(value.to_string()),
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+
+        Ok(Self { answers })
+    }
+
+    pub fn get(&self, day: Day, part: Part) -> Option<&Answer> {
+        self.answers.get(&(day, part))
+    }
+}
+
+#[derive(Debug)]
+pub enum Outcome {
+    Correct,
+    Incorrect { expected: Answer, actual: Answer },
+    Failed(AocError),
+}
+
+impl Outcome {
+    pub fn compare(expected: &Answer, result: Result<Answer, AocError>) -> Self {
+        match result {
+            Ok(actual) if actual == *expected => Self::Correct,
+            Ok(actual) => Self::Incorrect {
+                expected: expected.clone(),
+                actual,
+            },
+            Err(e) => Self::Failed(e),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Verification {
+    pub day: Day,
+    pub part: Part,
+    pub outcome: Outcome,
+}
+
+impl Verification {
+    pub fn is_correct(&self) -> bool {
+        matches!(self.outcome, Outcome::Correct)
+    }
+}
+
+// Checks every day and part that has both an input and a recorded answer.
+// Anything missing either is skipped, as inputs are not committed.
+pub fn verify(answers: &Answers, data_dir: &Path) -> Vec<Verification> {
+    let mut verifications = vec![];
+
+    for solution in days::solutions() {
+        let day = solution.day();
+        let input_path = crate::input_path(data_dir, day);
+        if !input_path.exists() {
+            continue;
+        }
+
+        for part in PARTS {
+            let Some(expected) = answers.get(day, part) else {
+                continue;
+            };
+
+            let result = crate::load_data(input_path.clone())
+                .map_err(AocError::from)
+                .and_then(|data| solution.run(part, &data));
+            let outcome = Outcome::compare(expected, result);
+            verifications.push(Verification { day, part, outcome });
+        }
+    }
+
+    verifications
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_string_and_integer_answers() {
+        let answers = Answers::parse(
+            r#"
+            [day01]
+            part1 = 280
+            part2 = "1797"
+
+            [day10]
+            part1 = 492982
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(1, 1), Some(&Answer::Signed(280)));
+        assert_eq!(answers.get(1, 2), Some(&Answer::from("1797")));
+        assert_eq!(answers.get(10, 1), Some(&Answer::Unsigned(492982)));
+        assert_eq!(answers.get(10, 2), None);
+        assert_eq!(answers.get(2, 1), None);
+    }
+
+    #[test]
+    fn rejects_unknown_sections() {
+        assert!(Answers::parse("[dayone]\npart1 = 1").is_err());
+    }
+}
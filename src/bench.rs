@@ -0,0 +1,142 @@
+use std::time::{Duration, Instant};
+
+use crate::days::Part;
+use crate::error::AocError;
+use crate::memory::{self, MemoryUsage};
+use crate::solution::DynSolution;
+
+#[derive(Debug, Clone)]
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub max_iterations: usize,
+    pub time_budget: Duration,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            max_iterations: 100,
+            time_budget: Duration::from_secs(10),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+    // the most seen by any timed run, if memory is being measured
+    pub memory: Option<MemoryUsage>,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let secs: Vec<f64> = sorted.iter().map(|d| d.as_secs_f64()).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Some(Self {
+            iterations: n,
+            min: sorted[0],
+            median: percentile(&sorted, 50),
+            mean: Duration::from_secs_f64(mean),
+            p95: percentile(&sorted, 95),
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+            memory: None,
+        })
+    }
+}
+
+// nearest rank percentile of already sorted samples
+fn percentile(sorted: &[Duration], p: usize) -> Duration {
+    let rank = (p * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+// Runs parse and solve together, as `crate::run` does, so the numbers
+// match what a single run of the binary would see minus file loading.
+pub fn bench(
+    solution: &dyn DynSolution,
+    part: Part,
+    data: &str,
+    options: &BenchOptions,
+) -> Result<Stats, AocError> {
+    let start = Instant::now();
+
+    for _ in 0..options.warmup {
+        solution.run(part, data)?;
+        if start.elapsed() >= options.time_budget {
+            break;
+        }
+    }
+
+    let start = Instant::now();
+    let mut samples = Vec::with_capacity(options.max_iterations);
+    let mut memory: Option<MemoryUsage> = None;
+    while samples.len() < options.max_iterations.max(1) {
+        let iteration_start = Instant::now();
+        let (result, usage) = memory::measure(|| solution.run(part, data));
+        result?;
+        samples.push(iteration_start.elapsed());
+
+        memory = match (memory, usage) {
+            (Some(seen), Some(usage)) => Some(seen.max(usage)),
+            (seen, usage) => seen.or(usage),
+        };
+
+        if start.elapsed() >= options.time_budget {
+            break;
+        }
+    }
+
+    let stats = Stats::from_samples(&samples).expect("at least one sample");
+    Ok(Stats { memory, ..stats })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn stats_from_samples() {
+        let samples: Vec<Duration> = [5, 1, 4, 2, 3].into_iter().map(ms).collect();
+        let stats = Stats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.iterations, 5);
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.mean, ms(3));
+        assert_eq!(stats.p95, ms(5));
+        assert_eq!(stats.std_dev.as_micros(), 1414);
+    }
+
+    #[test]
+    fn stats_from_no_samples() {
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn percentile_nearest_rank() {
+        let samples: Vec<Duration> = (1..=20).map(ms).collect();
+        assert_eq!(percentile(&samples, 50), ms(10));
+        assert_eq!(percentile(&samples, 95), ms(19));
+        assert_eq!(percentile(&samples, 100), ms(20));
+    }
+}
@@ -0,0 +1,89 @@
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use aoc2015::days::{self, PARTS};
+use aoc2015::parallel;
+use aoc2015::solution::{Context, DynSolution};
+
+use crate::build_file_path;
+use crate::format::{self, Table};
+use crate::progress::ProgressBar;
+use crate::report::{self, InputResult, OutputFormat};
+
+enum DayResult {
+    Skipped(String),
+    Ran(InputResult),
+}
+
+pub fn run(output: OutputFormat, jobs: usize, ctx: Context) -> ExitCode {
+    let jobs = if jobs == 0 {
+        parallel::available_jobs()
+    } else {
+        jobs
+    };
+
+    let progress_bar = ProgressBar::show_unless_parallel(&ctx.progress, jobs);
+    let start = Instant::now();
+    let results = parallel::map(days::solutions(), jobs, |solution| run_day(*solution, &ctx));
+    let wall_time = start.elapsed();
+    drop(progress_bar);
+
+    let mut headers = vec!["Day", "Title", "Part", "Answer", "Parse", "Solve"];
+    headers.extend(format::memory_headers());
+    let mut table = Table::new(headers);
+    let mut total_parse = Duration::ZERO;
+    let mut total_solve = Duration::ZERO;
+    let mut failed = false;
+
+    for (solution, result) in days::solutions().iter().zip(results) {
+        let day = solution.day();
+        let result = match result {
+            DayResult::Skipped(reason) => {
+                eprintln!("skipping day {}: {}", day, reason);
+                continue;
+            }
+            DayResult::Ran(result) => result,
+        };
+
+        let input = build_file_path(None, day).display().to_string();
+        let label = [day.to_string(), solution.title().to_string()];
+        let recorded = report::record(&mut table, output, day, &PARTS, &input, &label, result);
+        failed |= recorded.failed;
+        total_parse += recorded.parse_time;
+        total_solve += recorded.solve_time;
+    }
+
+    if let OutputFormat::Text = output {
+        let mut row = vec![
+            "Total".to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+            format::duration(total_parse),
+            format::duration(total_solve),
+        ];
+        row.extend(format::memory_cells(None));
+        table.push(row);
+        table.print();
+        println!(
+            "\nwall time {} across {} jobs, summed time {}",
+            format::duration(wall_time),
+            jobs,
+            format::duration(total_parse + total_solve)
+        );
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_day(solution: &dyn DynSolution, ctx: &Context) -> DayResult {
+    let file_path = build_file_path(None, solution.day());
+    if !file_path.exists() {
+        return DayResult::Skipped(format!("no input at {}", file_path.display()));
+    }
+    DayResult::Ran(InputResult::run(solution, &PARTS, file_path, ctx))
+}
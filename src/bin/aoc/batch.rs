@@ -0,0 +1,82 @@
+use std::path::Path;
+use std::process::ExitCode;
+
+use aoc2015::days::{self, Day, Part, PARTS};
+use aoc2015::error::AocError;
+use aoc2015::parallel;
+use aoc2015::solution::Context;
+
+use crate::format::{self, Table};
+use crate::input_files;
+use crate::progress::ProgressBar;
+use crate::report::{self, InputResult, OutputFormat};
+
+pub fn run(
+    day: Day,
+    part: Option<Part>,
+    dir: &Path,
+    output: OutputFormat,
+    jobs: usize,
+    ctx: Context,
+) -> ExitCode {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => PARTS.to_vec(),
+    };
+    let Some(solution) = days::find(day) else {
+        eprintln!(
+            "error: {}",
+            AocError::NotImplemented {
+                day,
+                part: parts[0]
+            }
+        );
+        return ExitCode::FAILURE;
+    };
+    let files = match input_files(dir) {
+        Ok(files) => files,
+        Err(e) => {
+            eprintln!("error: {}: {}", dir.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let jobs = if jobs == 0 {
+        parallel::available_jobs()
+    } else {
+        jobs
+    };
+
+    let progress_bar = ProgressBar::show_unless_parallel(&ctx.progress, jobs);
+    let results = parallel::map(&files, jobs, |file| {
+        InputResult::run(solution, &parts, file.clone(), &ctx)
+    });
+    drop(progress_bar);
+
+    let mut headers = vec!["File", "Part", "Answer", "Parse", "Solve"];
+    headers.extend(format::memory_headers());
+    let mut table = Table::new(headers);
+    let mut failed_files = 0;
+
+    for (file, result) in files.iter().zip(results) {
+        let input = file.display().to_string();
+        let name = file
+            .file_name()
+            .map_or(input.clone(), |name| name.to_string_lossy().to_string());
+
+        let recorded = report::record(&mut table, output, day, &parts, &input, &[name], result);
+        if recorded.failed {
+            failed_files += 1;
+        }
+    }
+
+    if let OutputFormat::Text = output {
+        table.print();
+        println!("\n{} of {} inputs failed", failed_files, files.len());
+    }
+
+    if failed_files > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
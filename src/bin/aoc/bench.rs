@@ -0,0 +1,59 @@
+use std::process::ExitCode;
+use std::time::Duration;
+
+use aoc2015::bench::{self, BenchOptions};
+use aoc2015::days::{self, Day, Part};
+use aoc2015::error::AocError;
+use aoc2015::input::InputSource;
+
+use crate::format;
+
+pub fn run(day: Day, part: Part, source: InputSource, options: BenchOptions) -> ExitCode {
+    match bench_day_part(day, part, source, &options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn bench_day_part(
+    day: Day,
+    part: Part,
+    source: InputSource,
+    options: &BenchOptions,
+) -> Result<(), AocError> {
+    let solution = days::find(day).ok_or(AocError::NotImplemented { day, part })?;
+    let data = source.read()?;
+
+    eprintln!(
+        "benchmarking day {} part {} ({} warmup, up to {} iterations or {})",
+        day,
+        part,
+        options.warmup,
+        options.max_iterations,
+        format::duration(options.time_budget)
+    );
+    let stats = bench::bench(solution, part, &data, options)?;
+
+    println!("iterations: {}", stats.iterations);
+    print_stat("min", stats.min);
+    print_stat("median", stats.median);
+    print_stat("mean", stats.mean);
+    print_stat("p95", stats.p95);
+    print_stat("std dev", stats.std_dev);
+    if let Some(memory) = stats.memory {
+        print_bytes("peak", memory.peak);
+        print_bytes("allocated", memory.total);
+    }
+    Ok(())
+}
+
+fn print_stat(label: &str, d: Duration) {
+    println!("{:<10} {}", format!("{}:", label), format::duration(d));
+}
+
+fn print_bytes(label: &str, n: u64) {
+    println!("{:<10} {}", format!("{}:", label), format::bytes(n));
+}
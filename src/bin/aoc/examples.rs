@@ -0,0 +1,72 @@
+use std::process::ExitCode;
+
+use aoc2015::answers::Outcome;
+use aoc2015::days::{self, Day};
+use aoc2015::error::AocError;
+use aoc2015::examples;
+
+pub fn run(day: Option<Day>, include_slow: bool) -> ExitCode {
+    let solutions = match day {
+        Some(day) => match days::find(day) {
+            Some(solution) => vec![solution],
+            None => {
+                eprintln!("error: {}", AocError::NotImplemented { day, part: 1 });
+                return ExitCode::FAILURE;
+            }
+        },
+        None => days::solutions().to_vec(),
+    };
+
+    let mut checked = 0;
+    let mut correct = 0;
+    let mut skipped = 0;
+    for solution in solutions {
+        if !include_slow {
+            skipped += solution.examples().iter().filter(|e| e.slow).count();
+        }
+
+        for check in examples::check(solution, include_slow) {
+            let label = format!(
+                "day {} part {} {}",
+                check.day,
+                check.example.part,
+                summary(check.example.input)
+            );
+            match &check.outcome {
+                Outcome::Correct => println!("{}: ok", label),
+                Outcome::Incorrect { expected, actual } => {
+                    println!("{}: MISMATCH expected {} got {}", label, expected, actual)
+                }
+                Outcome::Failed(e) => println!("{}: error: {}", label, e),
+            }
+
+            checked += 1;
+            if check.is_correct() {
+                correct += 1;
+            }
+        }
+    }
+
+    println!("{} of {} examples passed", correct, checked);
+    if skipped > 0 {
+        println!(
+            "skipped {} slow examples, run with --slow to include them",
+            skipped
+        );
+    }
+    if correct == checked {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+// first line of an example's input, cut short so each result fits on a line
+fn summary(input: &str) -> String {
+    let first_line = input.lines().next().unwrap_or_default();
+    let mut summary: String = first_line.chars().take(30).collect();
+    if summary.len() < input.len() {
+        summary.push_str("...");
+    }
+    format!("{:?}", summary)
+}
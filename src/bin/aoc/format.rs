@@ -0,0 +1,128 @@
+use std::time::Duration;
+
+use aoc2015::memory::{self, MemoryUsage};
+use aoc2015::runner::TimedRun;
+
+pub fn duration(d: Duration) -> String {
+    let micros = d.as_secs_f64() * 1_000_000.0;
+    if micros < 1_000.0 {
+        format!("{:.1}µs", micros)
+    } else if micros < 1_000_000.0 {
+        format!("{:.3}ms", micros / 1_000.0)
+    } else {
+        format!("{:.3}s", micros / 1_000_000.0)
+    }
+}
+
+pub fn solve_time(run: &TimedRun) -> String {
+    if run.cached {
+        "cached".to_string()
+    } else {
+        duration(run.solve_time)
+    }
+}
+
+pub fn timing(run: &TimedRun) -> String {
+    if run.cached {
+        "cached".to_string()
+    } else {
+        format!(
+            "parse {}, solve {}",
+            duration(run.parse_time),
+            duration(run.solve_time)
+        )
+    }
+}
+
+pub fn bytes(n: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut value = n as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{}B", n)
+    } else {
+        format!("{:.1}{}", value, UNITS[unit])
+    }
+}
+
+pub const MEMORY_HEADERS: [&str; 2] = ["Peak", "Allocated"];
+
+// table cells for `MEMORY_HEADERS`, or none at all when memory isn't being
+// measured so the columns can be left off entirely
+pub fn memory_cells(usage: Option<MemoryUsage>) -> Vec<String> {
+    if !memory::is_enabled() {
+        return vec![];
+    }
+    match usage {
+        Some(usage) => vec![bytes(usage.peak), bytes(usage.total)],
+        None => vec![String::new(), String::new()],
+    }
+}
+
+pub fn memory_headers() -> Vec<&'static str> {
+    if memory::is_enabled() {
+        MEMORY_HEADERS.to_vec()
+    } else {
+        vec![]
+    }
+}
+
+pub struct Table {
+    headers: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: Vec<&'static str>) -> Self {
+        Self {
+            headers,
+            rows: vec![],
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    pub fn print(&self) {
+        let widths: Vec<usize> = (0..self.headers.len())
+            .map(|i| {
+                self.rows
+                    .iter()
+                    .map(|row| row[i].chars().count())
+                    .chain([self.headers[i].chars().count()])
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        let headers: Vec<String> = self.headers.iter().map(|h| h.to_string()).collect();
+        print_row(&headers, &widths);
+        println!(
+            "{}",
+            widths
+                .iter()
+                .map(|w| "-".repeat(*w))
+                .collect::<Vec<_>>()
+                .join("-+-")
+        );
+        for row in self.rows.iter() {
+            print_row(row, &widths);
+        }
+    }
+}
+
+fn print_row(row: &[String], widths: &[usize]) {
+    let cells: Vec<String> = row
+        .iter()
+        .zip(widths.iter())
+        .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+        .collect();
+    println!("{}", cells.join(" | ").trim_end());
+}
@@ -0,0 +1,343 @@
+mod all;
+mod batch;
+mod bench;
+mod examples;
+mod format;
+mod parse;
+mod progress;
+mod repl;
+mod report;
+mod scaffold;
+mod serve;
+mod single;
+mod trace;
+mod verify;
+mod watch;
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use aoc2015::bench::BenchOptions;
+use aoc2015::cache;
+use aoc2015::days::{Day, Part};
+use aoc2015::input::InputSource;
+use aoc2015::memory::{self, CountingAllocator};
+use aoc2015::solution::Context;
+use clap::{ArgAction, Args, Parser, Subcommand};
+use report::OutputFormat;
+
+// only counts once --memory turns it on
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Parser)]
+#[command(subcommand_negates_reqs = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    run: RunArgs,
+
+    /// How results are printed
+    #[arg(short, long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    output: OutputFormat,
+
+    /// Give up on a part after this many seconds
+    #[arg(short, long, global = true, value_name = "SECS", value_parser = parse_seconds)]
+    timeout: Option<Duration>,
+
+    /// Measure peak and total bytes allocated by each run
+    #[arg(short, long, global = true)]
+    memory: bool,
+
+    /// Always solve, ignoring the cache in $AOC_CACHE_DIR. Cached answers are
+    /// reused for unchanged inputs, even after a solution changes
+    #[arg(long, global = true)]
+    no_cache: bool,
+
+    /// Log the time each parse and solve takes to stderr. Repeat for debug events
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+
+    /// Write parse and solve spans and debug events to this file as JSON lines
+    #[arg(long, global = true, value_name = "FILE")]
+    trace_file: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[arg(short, long, required = true, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<Day>,
+
+    /// Part to run. Runs both parts against one parsed input if omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<Part>,
+
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Input file, or - to read from stdin. Defaults to ./data/dayNN.txt
+    #[arg(short, long, value_name = "FILE")]
+    file: Option<PathBuf>,
+
+    /// Puzzle input given inline instead of from a file
+    #[arg(short, long, value_name = "TEXT", conflicts_with = "file")]
+    input: Option<String>,
+}
+
+impl InputArgs {
+    fn source(self, day: Day) -> InputSource {
+        match (self.input, self.file) {
+            (Some(text), _) => InputSource::Text(text),
+            (None, Some(file)) if file.as_os_str() == "-" => InputSource::Stdin,
+            (None, file) => InputSource::File(build_file_path(file, day)),
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run every implemented day and part against ./data/dayNN.txt
+    All {
+        /// Days to run at once. 0 uses every available core
+        #[arg(short, long, default_value_t = 1)]
+        jobs: usize,
+    },
+    /// Run a day against every input file in a directory
+    Batch {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Day,
+
+        /// Part to run. Runs both parts if omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<Part>,
+
+        /// Directory of input files
+        #[arg(long, value_name = "DIR")]
+        dir: PathBuf,
+
+        /// Inputs to run at once. 0 uses every available core
+        #[arg(short, long, default_value_t = 1)]
+        jobs: usize,
+    },
+    /// Repeatedly run a day and part and report timing statistics
+    Bench {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Day,
+
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Part,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Untimed runs before measuring
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+
+        /// Maximum number of timed runs
+        #[arg(long, default_value_t = 100)]
+        iterations: usize,
+
+        /// Stop measuring after this many seconds, even if fewer iterations have run
+        #[arg(long, value_name = "SECS", default_value = "10", value_parser = parse_seconds)]
+        time_budget: Duration,
+    },
+    /// Check answers for every available input against ./data/answers.toml
+    Verify {
+        #[arg(short, long, value_name = "FILE")]
+        answers: Option<PathBuf>,
+    },
+    /// Manage answers cached in $AOC_CACHE_DIR
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+    /// Check every day's answers against the examples from its puzzle text
+    Examples {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<Day>,
+
+        /// Also run examples that take a long time, such as day 4's
+        #[arg(long)]
+        slow: bool,
+    },
+    /// Generate and register a skeleton solution and test for a new day
+    New {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Day,
+    },
+    /// Run only a day's parser and print the model it builds as JSON
+    Parse {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Day,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Interactively load inputs, edit them and run parts
+    Repl,
+    /// Serve solutions over HTTP, answering POST /day/{day}/part/{part} with the body as input
+    Serve {
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+
+        /// 0 picks any free port
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+    },
+    /// Re-run a day whenever its input file, or any file in an examples directory, changes
+    Watch {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Day,
+
+        /// Part to run. Runs both parts if omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<Part>,
+
+        /// Input file. Defaults to ./data/dayNN.txt
+        #[arg(short, long, value_name = "FILE")]
+        file: Option<PathBuf>,
+
+        /// Directory of example inputs to run as well
+        #[arg(short, long, value_name = "DIR")]
+        examples: Option<PathBuf>,
+
+        /// Milliseconds between checks for changes
+        #[arg(long, value_name = "MS", default_value_t = 500)]
+        interval: u64,
+    },
+}
+
+#[derive(Subcommand)]
+enum CacheCommand {
+    /// Remove every cached answer
+    Clear,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if let Err(e) = trace::init(cli.verbose, cli.trace_file.as_deref()) {
+        eprintln!("error: can't create trace file: {}", e);
+        return ExitCode::FAILURE;
+    }
+    if cli.memory {
+        memory::enable();
+    }
+    if let Some(cache) = cache::from_env().filter(|_| !cli.no_cache) {
+        cache::enable(cache);
+    }
+    let ctx = match cli.timeout {
+        Some(timeout) => Context::with_timeout(timeout),
+        None => Context::default(),
+    };
+
+    match cli.command {
+        Some(Command::All { jobs }) => all::run(cli.output, jobs, ctx),
+        Some(Command::Batch {
+            day,
+            part,
+            dir,
+            jobs,
+        }) => batch::run(day, part, &dir, cli.output, jobs, ctx),
+        Some(Command::Bench {
+            day,
+            part,
+            input,
+            warmup,
+            iterations,
+            time_budget,
+        }) => {
+            let options = BenchOptions {
+                warmup,
+                max_iterations: iterations,
+                time_budget,
+            };
+            bench::run(day, part, input.source(day), options)
+        }
+        Some(Command::Verify { answers }) => verify::run(answers),
+        Some(Command::Cache {
+            command: CacheCommand::Clear,
+        }) => clear_cache(),
+        Some(Command::Examples { day, slow }) => examples::run(day, slow),
+        Some(Command::New { day }) => scaffold::run(day),
+        Some(Command::Parse { day, input }) => parse::run(day, input.source(day)),
+        Some(Command::Repl) => repl::run(ctx),
+        Some(Command::Serve { host, port }) => serve::run(&host, port, ctx),
+        Some(Command::Watch {
+            day,
+            part,
+            file,
+            examples,
+            interval,
+        }) => watch::run(
+            day,
+            part,
+            file,
+            examples,
+            Duration::from_millis(interval),
+            ctx,
+        ),
+        None => {
+            let day = cli.run.day.expect("required by clap");
+            let source = cli.run.input.source(day);
+            single::run(day, cli.run.part, source, cli.output, ctx)
+        }
+    }
+}
+
+fn clear_cache() -> ExitCode {
+    let Some(cache) = cache::from_env() else {
+        eprintln!("error: {} isn't set, so there is no cache", cache::DIR_VAR);
+        return ExitCode::FAILURE;
+    };
+    match cache.clear() {
+        Ok(removed) => {
+            println!(
+                "removed {} cached answers from {}",
+                removed,
+                cache.dir().display()
+            );
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// a positive, finite number of seconds that fits in a Duration
+fn parse_seconds(s: &str) -> Result<Duration, String> {
+    let secs: f64 = s.parse().map_err(|e| format!("{}", e))?;
+    if secs <= 0.0 {
+        return Err("must be more than 0 seconds".to_string());
+    }
+    Duration::try_from_secs_f64(secs).map_err(|e| format!("{}", e))
+}
+
+fn build_file_path(file_path_arg: Option<PathBuf>, day: Day) -> PathBuf {
+    if let Some(input_file) = file_path_arg.as_deref() {
+        PathBuf::from(input_file)
+    } else {
+        aoc2015::input_path(Path::new(aoc2015::DATA_DIR), day)
+    }
+}
+
+// every regular file directly in `dir`, in name order
+fn input_files(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
@@ -0,0 +1,30 @@
+use std::process::ExitCode;
+
+use aoc2015::days::{self, Day};
+use aoc2015::input::InputSource;
+
+// Runs only the day's parser and prints what it built as JSON, so inputs can
+// be inspected and parser changes diffed
+pub fn run(day: Day, source: InputSource) -> ExitCode {
+    let Some(solution) = days::find(day) else {
+        eprintln!("error: day {} not implemented", day);
+        return ExitCode::FAILURE;
+    };
+
+    let result = source.read().and_then(|data| solution.parsed_json(&data));
+    match result {
+        Ok(Some(model)) => {
+            let json = serde_json::to_string_pretty(&model).expect("model is serializable");
+            println!("{}", json);
+            ExitCode::SUCCESS
+        }
+        Ok(None) => {
+            eprintln!("day {} parsed, but has no model to show", day);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use std::io::{IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use aoc2015::progress::{Progress, Snapshot};
+
+const REFRESH: Duration = Duration::from_millis(100);
+// don't bother drawing anything for parts that finish quickly
+const DELAY: Duration = Duration::from_millis(300);
+const BAR_WIDTH: usize = 30;
+
+// Redraws solver progress on stderr until dropped. Drop it before printing
+// results so they don't end up on the same line.
+pub struct ProgressBar {
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl ProgressBar {
+    // Only when stderr is a terminal, so redirected output stays clean
+    pub fn show(progress: &Progress) -> Option<Self> {
+        std::io::stderr()
+            .is_terminal()
+            .then(|| Self::spawn(progress.clone()))
+    }
+
+    // Not when running several things at once, as their progress would
+    // clobber each other
+    pub fn show_unless_parallel(progress: &Progress, jobs: usize) -> Option<Self> {
+        if jobs == 1 {
+            Self::show(progress)
+        } else {
+            None
+        }
+    }
+
+    fn spawn(progress: Progress) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let handle = thread::spawn({
+            let stop = Arc::clone(&stop);
+            move || draw_until_stopped(&progress, &stop)
+        });
+
+        Self {
+            stop,
+            handle: Some(handle),
+        }
+    }
+}
+
+impl Drop for ProgressBar {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn draw_until_stopped(progress: &Progress, stop: &AtomicBool) {
+    let mut stderr = std::io::stderr();
+    let mut task = String::new();
+    let mut started = Instant::now();
+    let mut drawn = false;
+
+    while !stop.load(Ordering::Relaxed) {
+        thread::sleep(REFRESH);
+
+        let snapshot = progress.snapshot();
+        if snapshot.task != task {
+            task = snapshot.task.clone();
+            started = Instant::now();
+        }
+
+        let elapsed = started.elapsed();
+        if elapsed < DELAY || snapshot.unit.is_empty() {
+            continue;
+        }
+
+        let _ = write!(stderr, "\r\x1b[2K{}", render(&snapshot, elapsed));
+        let _ = stderr.flush();
+        drawn = true;
+    }
+
+    if drawn {
+        let _ = write!(stderr, "\r\x1b[2K");
+        let _ = stderr.flush();
+    }
+}
+
+fn render(snapshot: &Snapshot, elapsed: Duration) -> String {
+    let rate = snapshot.done as f64 / elapsed.as_secs_f64();
+    let counts = match snapshot.total {
+        Some(total) => {
+            let fraction = (snapshot.done as f64 / total as f64).min(1.0);
+            let filled = (fraction * BAR_WIDTH as f64) as usize;
+            format!(
+                "[{}{}] {:>3.0}% {}/{}",
+                "#".repeat(filled),
+                " ".repeat(BAR_WIDTH - filled),
+                fraction * 100.0,
+                human(snapshot.done as f64),
+                human(total as f64)
+            )
+        }
+        None => human(snapshot.done as f64),
+    };
+
+    format!(
+        "{}: {} {} ({} {}/s)",
+        snapshot.task,
+        counts,
+        snapshot.unit,
+        human(rate),
+        snapshot.unit
+    )
+}
+
+fn human(n: f64) -> String {
+    if n >= 1e9 {
+        format!("{:.2}G", n / 1e9)
+    } else if n >= 1e6 {
+        format!("{:.2}M", n / 1e6)
+    } else if n >= 1e3 {
+        format!("{:.1}k", n / 1e3)
+    } else {
+        format!("{:.0}", n)
+    }
+}
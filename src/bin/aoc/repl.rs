@@ -0,0 +1,325 @@
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aoc2015::days::day07::{Circuit, Day07};
+use aoc2015::days::{self, Day, Part, PARTS};
+use aoc2015::error::AocError;
+use aoc2015::input::InputSource;
+use aoc2015::runner;
+use aoc2015::solution::{Context, Solution};
+
+use crate::build_file_path;
+use crate::format;
+
+const HELP: &str = "\
+load <day> [file]      load a day's input, from ./data/dayNN.txt by default
+inline <day> <text>    use text as a day's input
+day <day>              switch to an already loaded day
+days                   list loaded days
+run [part]             run one or both parts against the current input
+lines [from] [to]      show lines of the current input
+set <n> <text>         replace line n
+insert <n> <text>      insert a line before line n
+delete <n>             delete line n
+reset                  undo edits to the current input
+wires                  day 7: list wires
+wire <label>           day 7: show a wire's signal
+override <label> <v>   day 7: force a wire to carry a signal
+clear                  day 7: remove all overrides
+help                   show this
+quit                   leave";
+
+struct LoadedInput {
+    source: String,
+    original: String,
+    lines: Vec<String>,
+    trailing_newline: bool,
+}
+
+impl LoadedInput {
+    fn new(source: String, data: String) -> Self {
+        let mut input = Self {
+            source,
+            original: data,
+            lines: vec![],
+            trailing_newline: false,
+        };
+        input.reset();
+        input
+    }
+
+    fn reset(&mut self) {
+        self.lines = self.original.lines().map(str::to_string).collect();
+        self.trailing_newline = self.original.ends_with('\n');
+    }
+
+    fn data(&self) -> String {
+        let mut data = self.lines.join("\n");
+        if self.trailing_newline {
+            data.push('\n');
+        }
+        data
+    }
+
+    fn is_modified(&self) -> bool {
+        self.data() != self.original
+    }
+}
+
+#[derive(Default)]
+struct Repl {
+    inputs: BTreeMap<Day, LoadedInput>,
+    current: Option<Day>,
+    // day 7's circuit for the current input, kept so overrides and resolved
+    // signals survive between commands
+    circuit: Option<Circuit>,
+    ctx: Context,
+}
+
+pub fn run(ctx: Context) -> ExitCode {
+    let mut repl = Repl {
+        ctx,
+        ..Repl::default()
+    };
+    let mut lines = io::stdin().lock().lines();
+
+    loop {
+        print!("{}> ", repl.prompt());
+        io::stdout().flush().expect("stdout is writable");
+
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            Some(Err(e)) => {
+                eprintln!("error: {}", e);
+                return ExitCode::FAILURE;
+            }
+            None => {
+                println!();
+                return ExitCode::SUCCESS;
+            }
+        };
+
+        match repl.execute(line.trim()) {
+            Ok(true) => {}
+            Ok(false) => return ExitCode::SUCCESS,
+            Err(e) => eprintln!("error: {}", e),
+        }
+    }
+}
+
+impl Repl {
+    fn prompt(&self) -> String {
+        match self.current {
+            Some(day) => format!("day{:02}", day),
+            None => "aoc".to_string(),
+        }
+    }
+
+    // false once the session should end
+    fn execute(&mut self, line: &str) -> Result<bool, String> {
+        let (command, args) = line.split_once(' ').unwrap_or((line, ""));
+        let args = args.trim();
+
+        match command {
+            "" => {}
+            "load" => {
+                let (day, file) = split_arg(args);
+                let day = parse_day(day)?;
+                let file = (!file.is_empty()).then(|| PathBuf::from(file));
+                let source = InputSource::File(build_file_path(file, day));
+                self.load(day, source)?;
+            }
+            "inline" => {
+                let (day, text) = split_arg(args);
+                let day = parse_day(day)?;
+                self.load(day, InputSource::Text(text.to_string()))?;
+            }
+            "day" => {
+                let day = parse_day(args)?;
+                if !self.inputs.contains_key(&day) {
+                    return Err(format!("day {} is not loaded", day));
+                }
+                self.current = Some(day);
+                self.circuit = None;
+            }
+            "days" => {
+                for (day, input) in self.inputs.iter() {
+                    let modified = if input.is_modified() { " (edited)" } else { "" };
+                    println!("{}: {}{}", day, input.source, modified);
+                }
+            }
+            "run" => {
+                let parts = match args {
+                    "" => PARTS.to_vec(),
+                    part => vec![parse_part(part)?],
+                };
+                self.run_parts(&parts)?;
+            }
+            "lines" => {
+                let (from, to) = split_arg(args);
+                self.show_lines(from, to)?;
+            }
+            "set" => {
+                let (n, text) = split_arg(args);
+                let i = self.line_index(n)?;
+                self.edit(|lines| lines[i] = text.to_string())?;
+            }
+            "insert" => {
+                let (n, text) = split_arg(args);
+                let n = parse_line_number(n)?;
+                let len = self.current_input()?.lines.len();
+                if n > len + 1 {
+                    return Err(format!("no line {}, input has {} lines", n, len));
+                }
+                self.edit(|lines| lines.insert(n - 1, text.to_string()))?;
+            }
+            "delete" => {
+                let i = self.line_index(args)?;
+                self.edit(|lines| {
+                    lines.remove(i);
+                })?;
+            }
+            "reset" => {
+                self.current_input_mut()?.reset();
+                self.circuit = None;
+            }
+            "wires" => println!("{}", self.circuit()?.wires().join(" ")),
+            "wire" => {
+                let signal = self.circuit()?.signal(args).map_err(|e| e.to_string())?;
+                println!("{}", signal);
+            }
+            "override" => {
+                let (wire, value) = split_arg(args);
+                let value: u16 = value
+                    .parse()
+                    .map_err(|_| format!("invalid signal {:?}", value))?;
+                self.circuit()?
+                    .override_wire(wire, value)
+                    .map_err(|e| e.to_string())?;
+            }
+            "clear" => self.circuit()?.clear_overrides(),
+            "help" => println!("{}", HELP),
+            "quit" | "exit" => return Ok(false),
+            _ => return Err(format!("unknown command {:?}, try help", command)),
+        }
+        Ok(true)
+    }
+
+    fn load(&mut self, day: Day, source: InputSource) -> Result<(), String> {
+        if days::find(day).is_none() {
+            return Err(AocError::NotImplemented { day, part: 1 }.to_string());
+        }
+        let name = source.to_string();
+        let data = source.read().map_err(|e| e.to_string())?;
+
+        let input = LoadedInput::new(name, data);
+        println!("loaded {} lines from {}", input.lines.len(), input.source);
+        self.inputs.insert(day, input);
+        self.current = Some(day);
+        self.circuit = None;
+        Ok(())
+    }
+
+    fn run_parts(&self, parts: &[Part]) -> Result<(), String> {
+        let day = self.current_day()?;
+        let solution = days::find(day).expect("only implemented days are loaded");
+        let data = self.current_input()?.data();
+
+        let timed = runner::run_parts_timed(solution, parts, &data, &self.ctx)
+            .map_err(|e| e.to_string())?;
+        for (run, part) in timed.runs.into_iter().zip(parts) {
+            match run {
+                Ok(run) => println!("part {}: {}  {}", part, run.answer, format::timing(&run)),
+                Err(e) => println!("part {}: error: {}", part, e),
+            }
+        }
+        Ok(())
+    }
+
+    fn show_lines(&self, from: &str, to: &str) -> Result<(), String> {
+        let lines = &self.current_input()?.lines;
+        let from = match from {
+            "" => 1,
+            n => parse_line_number(n)?,
+        };
+        let to = match to {
+            "" if from == 1 => lines.len(),
+            "" => from,
+            n => parse_line_number(n)?,
+        };
+
+        for (i, line) in lines.iter().enumerate().take(to).skip(from - 1) {
+            println!("{:>5}  {}", i + 1, line);
+        }
+        Ok(())
+    }
+
+    fn edit(&mut self, f: impl FnOnce(&mut Vec<String>)) -> Result<(), String> {
+        f(&mut self.current_input_mut()?.lines);
+        self.circuit = None;
+        Ok(())
+    }
+
+    fn circuit(&mut self) -> Result<&mut Circuit, String> {
+        if self.current_day()? != Day07::DAY {
+            return Err("wires are only available for day 7".to_string());
+        }
+        if self.circuit.is_none() {
+            let data = self.current_input()?.data();
+            self.circuit = Some(Day07::parse(&data).map_err(|e| e.to_string())?);
+        }
+        Ok(self.circuit.as_mut().expect("just parsed"))
+    }
+
+    fn line_index(&self, n: &str) -> Result<usize, String> {
+        let n = parse_line_number(n)?;
+        let len = self.current_input()?.lines.len();
+        if n > len {
+            return Err(format!("no line {}, input has {} lines", n, len));
+        }
+        Ok(n - 1)
+    }
+
+    fn current_day(&self) -> Result<Day, String> {
+        self.current
+            .ok_or_else(|| "no input loaded, try load <day>".to_string())
+    }
+
+    fn current_input(&self) -> Result<&LoadedInput, String> {
+        let day = self.current_day()?;
+        Ok(&self.inputs[&day])
+    }
+
+    fn current_input_mut(&mut self) -> Result<&mut LoadedInput, String> {
+        let day = self.current_day()?;
+        Ok(self.inputs.get_mut(&day).expect("current day is loaded"))
+    }
+}
+
+fn split_arg(args: &str) -> (&str, &str) {
+    let (first, rest) = args.split_once(' ').unwrap_or((args, ""));
+    (first, rest.trim_start())
+}
+
+fn parse_day(s: &str) -> Result<Day, String> {
+    match s.parse::<Day>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("invalid day {:?}", s)),
+    }
+}
+
+fn parse_part(s: &str) -> Result<Part, String> {
+    match s.parse::<Part>() {
+        Ok(part) if PARTS.contains(&part) => Ok(part),
+        _ => Err(format!("invalid part {:?}", s)),
+    }
+}
+
+fn parse_line_number(s: &str) -> Result<usize, String> {
+    match s.parse::<usize>() {
+        Ok(n) if n >= 1 => Ok(n),
+        _ => Err(format!("invalid line number {:?}", s)),
+    }
+}
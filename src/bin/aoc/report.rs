@@ -0,0 +1,265 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use aoc2015::answer::Answer;
+use aoc2015::days::{Day, Part};
+use aoc2015::error::AocError;
+use aoc2015::input;
+use aoc2015::runner::{self, TimedParts, TimedRun};
+use aoc2015::solution::{Context, DynSolution};
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::format::{self, Table};
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+#[derive(Serialize)]
+pub struct Report {
+    pub day: Day,
+    pub part: Part,
+    pub input: String,
+    pub input_hash: Option<String>,
+    #[serde(flatten)]
+    pub outcome: Outcome,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum Outcome {
+    Success {
+        answer: Answer,
+        timing: Timing,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        memory: Option<MemoryReport>,
+        #[serde(skip_serializing_if = "std::ops::Not::not")]
+        cached: bool,
+    },
+    Failure {
+        error: ErrorReport,
+    },
+}
+
+#[derive(Serialize)]
+pub struct Timing {
+    pub parse_ms: f64,
+    pub solve_ms: f64,
+}
+
+#[derive(Serialize)]
+pub struct MemoryReport {
+    pub parse_peak_bytes: u64,
+    pub parse_allocated_bytes: u64,
+    pub solve_peak_bytes: u64,
+    pub solve_allocated_bytes: u64,
+}
+
+#[derive(Serialize)]
+pub struct ErrorReport {
+    pub kind: &'static str,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
+}
+
+impl Report {
+    pub fn new(day: Day, part: Part, input: String, data: Option<&str>, outcome: Outcome) -> Self {
+        Self {
+            day,
+            part,
+            input,
+            input_hash: data.map(input::hash),
+            outcome,
+        }
+    }
+
+    pub fn print_json(&self) {
+        println!(
+            "{}",
+            serde_json::to_string(self).expect("report is serializable")
+        );
+    }
+}
+
+impl Outcome {
+    pub fn failure(e: &AocError) -> Self {
+        Self::Failure {
+            error: ErrorReport::from(e),
+        }
+    }
+}
+
+impl From<Result<TimedRun, AocError>> for Outcome {
+    fn from(result: Result<TimedRun, AocError>) -> Self {
+        match result {
+            Ok(run) => Self::Success {
+                answer: run.answer,
+                timing: Timing {
+                    parse_ms: millis(run.parse_time),
+                    solve_ms: millis(run.solve_time),
+                },
+                memory: run
+                    .parse_memory
+                    .zip(run.solve_memory)
+                    .map(|(parse, solve)| MemoryReport {
+                        parse_peak_bytes: parse.peak,
+                        parse_allocated_bytes: parse.total,
+                        solve_peak_bytes: solve.peak,
+                        solve_allocated_bytes: solve.total,
+                    }),
+                cached: run.cached,
+            },
+            Err(e) => Self::failure(&e),
+        }
+    }
+}
+
+impl From<&AocError> for ErrorReport {
+    fn from(e: &AocError) -> Self {
+        let (line, column) = match e {
+            AocError::Parse { line, column, .. } => (Some(*line), Some(*column)),
+            _ => (None, None),
+        };
+
+        Self {
+            kind: e.kind(),
+            message: e.to_string(),
+            line,
+            column,
+        }
+    }
+}
+
+// Running some parts of a day against one input file
+pub enum InputResult {
+    // nothing could be run, with the data if it was read
+    Failed(Option<String>, AocError),
+    Ran(String, TimedParts),
+}
+
+impl InputResult {
+    pub fn run(solution: &dyn DynSolution, parts: &[Part], file: PathBuf, ctx: &Context) -> Self {
+        let data = match aoc2015::load_data(file) {
+            Ok(data) => data,
+            Err(e) => return Self::Failed(None, e.into()),
+        };
+
+        match runner::run_parts_timed(solution, parts, &data, ctx) {
+            Ok(timed) => Self::Ran(data, timed),
+            Err(e) => Self::Failed(Some(data), e),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Recorded {
+    pub failed: bool,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+// Adds a row per part to `table`, each starting with the `label` cells and
+// followed by the part, answer, timing and memory columns, or prints a JSON
+// report per part instead
+pub fn record(
+    table: &mut Table,
+    output: OutputFormat,
+    day: Day,
+    parts: &[Part],
+    input: &str,
+    label: &[String],
+    result: InputResult,
+) -> Recorded {
+    let row = |part: Part, answer: String, parse: String, solve: String| {
+        let mut row = label.to_vec();
+        row.extend([part.to_string(), answer, parse, solve]);
+        row
+    };
+
+    let (data, timed) = match result {
+        InputResult::Failed(data, e) => {
+            for &part in parts {
+                match output {
+                    OutputFormat::Text => {
+                        let mut row =
+                            row(part, format!("error: {}", e), String::new(), String::new());
+                        row.extend(format::memory_cells(None));
+                        table.push(row);
+                    }
+                    OutputFormat::Json => {
+                        Report::new(
+                            day,
+                            part,
+                            input.to_string(),
+                            data.as_deref(),
+                            Outcome::failure(&e),
+                        )
+                        .print_json();
+                    }
+                }
+            }
+            return Recorded {
+                failed: true,
+                ..Recorded::default()
+            };
+        }
+        InputResult::Ran(data, timed) => (data, timed),
+    };
+
+    let mut recorded = Recorded {
+        parse_time: timed.parse_time,
+        ..Recorded::default()
+    };
+    let cached = timed.is_cached();
+
+    for (i, (run, &part)) in timed.runs.into_iter().zip(parts).enumerate() {
+        recorded.failed |= run.is_err();
+
+        if let OutputFormat::Json = output {
+            Report::new(day, part, input.to_string(), Some(&data), run.into()).print_json();
+            continue;
+        }
+
+        // the input is parsed once, so only show it against the first part
+        let parse_time = if i == 0 && cached {
+            "cached".to_string()
+        } else if i == 0 {
+            format::duration(timed.parse_time)
+        } else {
+            String::new()
+        };
+
+        // memory columns are for the solve, parse memory is only in the json output
+        let (mut row, memory) = match run {
+            Ok(run) => {
+                recorded.solve_time += run.solve_time;
+                (
+                    row(
+                        part,
+                        run.answer.to_string(),
+                        parse_time,
+                        format::solve_time(&run),
+                    ),
+                    run.solve_memory,
+                )
+            }
+            Err(e) => (
+                row(part, format!("error: {}", e), parse_time, String::new()),
+                None,
+            ),
+        };
+        row.extend(format::memory_cells(memory));
+        table.push(row);
+    }
+    recorded
+}
+
+fn millis(d: Duration) -> f64 {
+    d.as_secs_f64() * 1_000.0
+}
@@ -0,0 +1,211 @@
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+
+use aoc2015::days::Day;
+
+const DAYS_FILE: &str = "src/days.rs";
+
+const SOLUTION_TEMPLATE: &str = r#"use crate::answer::Answer;
+use crate::error::AocError;
+use crate::solution::{Context, Example, Solution};
+
+pub struct Day{NN};
+
+impl Solution for Day{NN} {
+    const DAY: u8 = {N};
+    const TITLE: &'static str = "TODO";
+    const EXAMPLES: &'static [Example] = &[];
+
+    type Input = Vec<String>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        parser::parse(data)
+    }
+
+    fn part1(_input: &Self::Input, _ctx: &Context) -> Result<Answer, AocError> {
+        Err(AocError::NotImplemented {
+            day: Self::DAY,
+            part: 1,
+        })
+    }
+
+    fn part2(_input: &Self::Input, _ctx: &Context) -> Result<Answer, AocError> {
+        Err(AocError::NotImplemented {
+            day: Self::DAY,
+            part: 2,
+        })
+    }
+}
+
+mod parser {
+    use nom::{character::complete::not_line_ending, combinator::map, IResult};
+
+    use super::*;
+    use crate::parsing;
+
+    pub fn parse(s: &str) -> Result<Vec<String>, AocError> {
+        parsing::parse_lines(s, line)
+    }
+
+    fn line(s: &str) -> IResult<&str, String> {
+        map(not_line_ending, str::to_string)(s)
+    }
+}
+"#;
+
+const TEST_TEMPLATE: &str = r#"use aoc2015::answer::Answer;
+use aoc2015::days::day{NN}::Day{NN};
+use aoc2015::solution::Solution;
+
+// not implemented yet
+#[ignore]
+#[test]
+fn part1_example_data() {
+    assert_eq!(Day{NN}::solve_part1("").unwrap(), Answer::from(0));
+}
+
+#[ignore]
+#[test]
+fn part2_example_data() {
+    assert_eq!(Day{NN}::solve_part2("").unwrap(), Answer::from(0));
+}
+"#;
+
+// Writes a skeleton solution and test for `day` and registers it in
+// src/days.rs. Paths are relative to the crate root, which is expected to be
+// the working directory, as with ./data.
+pub fn run(day: Day) -> ExitCode {
+    match scaffold(Path::new("."), day) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn scaffold(root: &Path, day: Day) -> Result<(), String> {
+    let days_file = root.join(DAYS_FILE);
+    let solution_file = root.join(format!("src/days/day{:02}.rs", day));
+    let test_file = root.join(format!("tests/day{:02}_test.rs", day));
+
+    let days = fs::read_to_string(&days_file).map_err(|e| {
+        format!(
+            "{}: {}, run this from the crate root",
+            days_file.display(),
+            e
+        )
+    })?;
+    let days = register(&days, day)?;
+
+    // check everything before writing anything, so a refusal leaves no trace
+    for file in [&solution_file, &test_file] {
+        if file.exists() {
+            return Err(format!("{} already exists", file.display()));
+        }
+    }
+
+    for (file, contents) in [
+        (&solution_file, fill(SOLUTION_TEMPLATE, day)),
+        (&test_file, fill(TEST_TEMPLATE, day)),
+        (&days_file, days),
+    ] {
+        fs::write(file, contents).map_err(|e| format!("{}: {}", file.display(), e))?;
+        println!("wrote {}", file.display());
+    }
+    Ok(())
+}
+
+fn fill(template: &str, day: Day) -> String {
+    template
+        .replace("{NN}", &format!("{:02}", day))
+        .replace("{N}", &day.to_string())
+}
+
+// Adds `pub mod dayNN;` and an entry in SOLUTIONS, each after the last
+// earlier day so both lists stay in order
+fn register(days: &str, day: Day) -> Result<String, String> {
+    let module = format!("pub mod day{:02};", day);
+    let entry = format!("    &day{0:02}::Day{0:02},", day);
+
+    let mut lines: Vec<&str> = days.lines().collect();
+    if lines.contains(&module.as_str()) {
+        return Err(format!(
+            "day {} is already registered in {}",
+            day, DAYS_FILE
+        ));
+    }
+
+    let module_at = insert_position(&lines, "pub mod day", &module)
+        .ok_or(format!("no day modules found in {}", DAYS_FILE))?;
+    lines.insert(module_at, &module);
+
+    let entry_at = insert_position(&lines, "    &day", &entry)
+        .ok_or(format!("no SOLUTIONS entries found in {}", DAYS_FILE))?;
+    lines.insert(entry_at, &entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+// after the last line starting with `prefix` that sorts before `line`, or
+// before the first one if none do
+fn insert_position(lines: &[&str], prefix: &str, line: &str) -> Option<usize> {
+    let matching: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with(prefix))
+        .collect();
+    let first = *matching.first()?;
+
+    Some(
+        matching
+            .iter()
+            .rev()
+            .find(|&&i| lines[i] < line)
+            .map_or(first, |&i| i + 1),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAYS: &str = "pub mod day01;
+pub mod day03;
+
+static SOLUTIONS: &[&dyn DynSolution] = &[
+    &day01::Day01,
+    &day03::Day03,
+];
+";
+
+    #[test]
+    fn registers_in_order() {
+        assert_eq!(
+            register(DAYS, 2).unwrap(),
+            "pub mod day01;
+pub mod day02;
+pub mod day03;
+
+static SOLUTIONS: &[&dyn DynSolution] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+];
+"
+        );
+        assert!(register(DAYS, 11).unwrap().contains(
+            "pub mod day03;
+pub mod day11;"
+        ));
+        assert!(register(DAYS, 11).unwrap().contains(
+            "    &day03::Day03,
+    &day11::Day11,
+];"
+        ));
+    }
+
+    #[test]
+    fn refuses_registered_day() {
+        assert!(register(DAYS, 3).is_err());
+    }
+}
@@ -0,0 +1,150 @@
+use std::process::ExitCode;
+use std::thread;
+
+use aoc2015::days::{self, Day, Part, PARTS};
+use aoc2015::error::AocError;
+use aoc2015::runner;
+use aoc2015::solution::Context;
+use serde::Serialize;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::report::{ErrorReport, Outcome, Report};
+
+// what the report's input field shows, as the input has no path
+const REQUEST_INPUT: &str = "<request>";
+
+#[derive(Serialize)]
+struct DayInfo {
+    day: Day,
+    title: &'static str,
+}
+
+#[derive(Serialize)]
+struct RouteError {
+    error: ErrorReport,
+}
+
+// Serves
+//   GET  /days                    every implemented day and its title
+//   POST /day/{day}/part/{part}   solves the request body as input
+// Both respond with JSON, solutions in the same shape as `--output json`.
+pub fn run(host: &str, port: u16, ctx: Context) -> ExitCode {
+    let server = match Server::http((host, port)) {
+        Ok(server) => server,
+        Err(e) => {
+            eprintln!("error: can't listen on {}:{}: {}", host, port, e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // the real port matters when asked for port 0
+    match server.server_addr().to_ip() {
+        Some(addr) => println!("listening on http://{}", addr),
+        None => println!("listening on {}:{}", host, port),
+    }
+
+    for request in server.incoming_requests() {
+        let ctx = ctx.clone();
+        thread::spawn(move || {
+            if let Err(e) = handle(request, &ctx) {
+                eprintln!("error: responding: {}", e);
+            }
+        });
+    }
+    ExitCode::SUCCESS
+}
+
+fn handle(mut request: Request, ctx: &Context) -> std::io::Result<()> {
+    let path = request
+        .url()
+        .split('?')
+        .next()
+        .unwrap_or_default()
+        .to_string();
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+    let (status, body) = match (request.method(), segments.as_slice()) {
+        (Method::Get, ["days"]) => {
+            let days: Vec<DayInfo> = days::solutions()
+                .iter()
+                .map(|s| DayInfo {
+                    day: s.day(),
+                    title: s.title(),
+                })
+                .collect();
+            (200, to_json(&days))
+        }
+        (Method::Post, ["day", day, "part", part]) => {
+            match (day.parse::<Day>(), part.parse::<Part>()) {
+                (Ok(day), Ok(part)) if PARTS.contains(&part) => {
+                    let mut data = String::new();
+                    match request.as_reader().read_to_string(&mut data) {
+                        Ok(_) => solve(day, part, &data, ctx),
+                        Err(e) => route_error(400, "bad_request", e.to_string()),
+                    }
+                }
+                _ => route_error(404, "not_found", format!("no route for {}", path)),
+            }
+        }
+        (_, ["days"] | ["day", _, "part", _]) => route_error(
+            405,
+            "method_not_allowed",
+            format!("{} not allowed on {}", request.method(), path),
+        ),
+        _ => route_error(404, "not_found", format!("no route for {}", path)),
+    };
+
+    let content_type = Header::from_bytes("Content-Type", "application/json")
+        .expect("content type header is valid");
+    request.respond(
+        Response::from_string(body)
+            .with_status_code(status)
+            .with_header(content_type),
+    )
+}
+
+fn solve(day: Day, part: Part, data: &str, ctx: &Context) -> (u16, String) {
+    let result = match days::find(day) {
+        Some(solution) => runner::run_timed(solution, part, data, ctx),
+        None => Err(AocError::NotImplemented { day, part }),
+    };
+    let status = match &result {
+        Ok(_) => 200,
+        Err(e) => error_status(e),
+    };
+
+    let report = Report::new(
+        day,
+        part,
+        REQUEST_INPUT.to_string(),
+        Some(data),
+        Outcome::from(result),
+    );
+    (status, to_json(&report))
+}
+
+fn error_status(e: &AocError) -> u16 {
+    match e {
+        AocError::Parse { .. } => 400,
+        AocError::NotImplemented { .. } => 404,
+        AocError::Unsolvable(_) => 422,
+        AocError::TimedOut(_) | AocError::Cancelled => 503,
+        AocError::Io(_) => 500,
+    }
+}
+
+fn route_error(status: u16, kind: &'static str, message: String) -> (u16, String) {
+    let error = RouteError {
+        error: ErrorReport {
+            kind,
+            message,
+            line: None,
+            column: None,
+        },
+    };
+    (status, to_json(&error))
+}
+
+fn to_json(value: &impl Serialize) -> String {
+    serde_json::to_string(value).expect("response is serializable")
+}
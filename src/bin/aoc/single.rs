@@ -0,0 +1,141 @@
+use std::process::ExitCode;
+
+use aoc2015::days::{self, Day, Part, PARTS};
+use aoc2015::error::AocError;
+use aoc2015::input::InputSource;
+use aoc2015::runner::{self, TimedParts};
+use aoc2015::solution::Context;
+
+use crate::format::{self, Table};
+use crate::progress::ProgressBar;
+use crate::report::{Outcome, OutputFormat, Report};
+
+pub fn run(
+    day: Day,
+    part: Option<Part>,
+    source: InputSource,
+    output: OutputFormat,
+    ctx: Context,
+) -> ExitCode {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => PARTS.to_vec(),
+    };
+    let input = source.to_string();
+
+    let Some(solution) = days::find(day) else {
+        let e = AocError::NotImplemented {
+            day,
+            part: parts[0],
+        };
+        return fail(day, &parts, input, None, &e, output);
+    };
+
+    let data = match source.read() {
+        Ok(data) => data,
+        Err(e) => return fail(day, &parts, input, None, &e, output),
+    };
+
+    let progress_bar = ProgressBar::show(&ctx.progress);
+    let result = runner::run_parts_timed(solution, &parts, &data, &ctx);
+    drop(progress_bar);
+
+    let timed = match result {
+        Ok(timed) => timed,
+        Err(e) => return fail(day, &parts, input, Some(&data), &e, output),
+    };
+    let success = timed.runs.iter().all(|run| run.is_ok());
+
+    match output {
+        OutputFormat::Text if part.is_some() => print_answer(timed),
+        OutputFormat::Text => print_table(timed),
+        OutputFormat::Json => {
+            for (run, part) in timed.runs.into_iter().zip(parts) {
+                Report::new(day, part, input.clone(), Some(&data), run.into()).print_json();
+            }
+        }
+    }
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn print_answer(timed: TimedParts) {
+    for run in timed.runs {
+        match run {
+            Ok(run) => {
+                println!("{}", run.answer);
+                if let (Some(parse), Some(solve)) = (run.parse_memory, run.solve_memory) {
+                    eprintln!(
+                        "memory: parse peak {} allocated {}, solve peak {} allocated {}",
+                        format::bytes(parse.peak),
+                        format::bytes(parse.total),
+                        format::bytes(solve.peak),
+                        format::bytes(solve.total)
+                    );
+                }
+            }
+            Err(e) => eprintln!("error: {}", e),
+        }
+    }
+}
+
+fn print_table(timed: TimedParts) {
+    let mut headers = vec!["Part", "Answer", "Time"];
+    headers.extend(format::memory_headers());
+    let mut table = Table::new(headers);
+
+    let mut row = vec![
+        "parse".to_string(),
+        String::new(),
+        if timed.is_cached() {
+            "cached".to_string()
+        } else {
+            format::duration(timed.parse_time)
+        },
+    ];
+    row.extend(format::memory_cells(timed.parse_memory));
+    table.push(row);
+
+    for (run, part) in timed.runs.into_iter().zip(PARTS) {
+        let (mut row, memory) = match run {
+            Ok(run) => (
+                vec![
+                    part.to_string(),
+                    run.answer.to_string(),
+                    format::solve_time(&run),
+                ],
+                run.solve_memory,
+            ),
+            Err(e) => (
+                vec![part.to_string(), format!("error: {}", e), String::new()],
+                None,
+            ),
+        };
+        row.extend(format::memory_cells(memory));
+        table.push(row);
+    }
+    table.print();
+}
+
+fn fail(
+    day: Day,
+    parts: &[Part],
+    input: String,
+    data: Option<&str>,
+    e: &AocError,
+    output: OutputFormat,
+) -> ExitCode {
+    match output {
+        OutputFormat::Text => eprintln!("error: {}", e),
+        OutputFormat::Json => {
+            for part in parts {
+                Report::new(day, *part, input.clone(), data, Outcome::failure(e)).print_json();
+            }
+        }
+    }
+    ExitCode::FAILURE
+}
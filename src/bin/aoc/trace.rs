@@ -0,0 +1,42 @@
+use std::fs::File;
+use std::io::{self, IsTerminal};
+use std::path::Path;
+use std::sync::Mutex;
+
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::prelude::*;
+
+// Logs to stderr, keeping stdout for results. With no -v only warnings show;
+// -v adds the time each parse and solve took and -vv every debug event. A
+// trace file gets everything down to debug events as JSON lines, whatever the
+// verbosity.
+pub fn init(verbosity: u8, trace_file: Option<&Path>) -> io::Result<()> {
+    let level = match verbosity {
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::INFO,
+        _ => LevelFilter::DEBUG,
+    };
+    let stderr = tracing_subscriber::fmt::layer()
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .with_span_events(FmtSpan::CLOSE)
+        .with_filter(level);
+
+    let json = match trace_file {
+        Some(path) => Some(
+            tracing_subscriber::fmt::layer()
+                .json()
+                .with_writer(Mutex::new(File::create(path)?))
+                .with_span_events(FmtSpan::CLOSE)
+                .with_filter(LevelFilter::DEBUG),
+        ),
+        None => None,
+    };
+
+    tracing_subscriber::registry()
+        .with(stderr)
+        .with(json)
+        .init();
+    Ok(())
+}
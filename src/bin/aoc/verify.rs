@@ -0,0 +1,38 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aoc2015::answers::{self, Answers, Outcome};
+
+pub fn run(answers_file: Option<PathBuf>) -> ExitCode {
+    let data_dir = Path::new(aoc2015::DATA_DIR);
+    let answers_file = answers_file.unwrap_or_else(|| data_dir.join(answers::ANSWERS_FILE));
+
+    let answers = match Answers::load(&answers_file) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("error: {}: {}", answers_file.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let verifications = answers::verify(&answers, data_dir);
+    for v in verifications.iter() {
+        match &v.outcome {
+            Outcome::Correct => println!("day {} part {}: ok", v.day, v.part),
+            Outcome::Incorrect { expected, actual } => println!(
+                "day {} part {}: MISMATCH expected {} got {}",
+                v.day, v.part, expected, actual
+            ),
+            Outcome::Failed(e) => println!("day {} part {}: error: {}", v.day, v.part, e),
+        }
+    }
+
+    let correct = verifications.iter().filter(|v| v.is_correct()).count();
+    println!("{} of {} answers verified", correct, verifications.len());
+
+    if correct == verifications.len() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
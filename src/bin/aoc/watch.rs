@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use aoc2015::days::{self, Day, Part, PARTS};
+use aoc2015::error::AocError;
+use aoc2015::runner;
+use aoc2015::solution::{Context, DynSolution};
+
+use crate::format;
+use crate::{build_file_path, input_files};
+
+// Enough to notice most edits without reading every file on every poll
+#[derive(PartialEq)]
+struct Fingerprint {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+pub fn run(
+    day: Day,
+    part: Option<Part>,
+    file: Option<PathBuf>,
+    examples: Option<PathBuf>,
+    interval: Duration,
+    ctx: Context,
+) -> ExitCode {
+    let Some(solution) = days::find(day) else {
+        eprintln!(
+            "error: {}",
+            AocError::NotImplemented {
+                day,
+                part: part.unwrap_or(1)
+            }
+        );
+        return ExitCode::FAILURE;
+    };
+    let parts = match part {
+        Some(part) => vec![part],
+        None => PARTS.to_vec(),
+    };
+    let input_path = build_file_path(file, day);
+
+    eprintln!(
+        "watching {}{} every {}, ctrl-c to stop",
+        input_path.display(),
+        examples
+            .as_ref()
+            .map_or(String::new(), |dir| format!(" and {}", dir.display())),
+        format::duration(interval)
+    );
+
+    let mut fingerprints: HashMap<PathBuf, Option<Fingerprint>> = HashMap::new();
+    let mut answers: HashMap<(PathBuf, Part), String> = HashMap::new();
+
+    loop {
+        let mut paths = vec![input_path.clone()];
+        if let Some(dir) = examples.as_deref() {
+            // the directory may be briefly missing while being edited
+            paths.extend(input_files(dir).unwrap_or_default());
+        }
+
+        for path in paths {
+            let fingerprint = fingerprint(&path);
+            if fingerprints.get(&path) == Some(&fingerprint) {
+                continue;
+            }
+            let missing = fingerprint.is_none();
+            fingerprints.insert(path.clone(), fingerprint);
+
+            if missing {
+                println!("{}: missing", path.display());
+                continue;
+            }
+            rerun(solution, &parts, &path, &ctx, &mut answers);
+        }
+
+        thread::sleep(interval);
+    }
+}
+
+fn rerun(
+    solution: &dyn DynSolution,
+    parts: &[Part],
+    path: &Path,
+    ctx: &Context,
+    answers: &mut HashMap<(PathBuf, Part), String>,
+) {
+    let result = aoc2015::load_data(path.to_path_buf())
+        .map_err(AocError::from)
+        .and_then(|data| runner::run_parts_timed(solution, parts, &data, ctx));
+
+    let timed = match result {
+        Ok(timed) => timed,
+        Err(e) => {
+            println!("{}: error: {}", path.display(), e);
+            return;
+        }
+    };
+
+    for (run, part) in timed.runs.into_iter().zip(parts) {
+        let (answer, timing) = match run {
+            Ok(run) => (
+                run.answer.to_string(),
+                format!("  {}", format::timing(&run)),
+            ),
+            Err(e) => (format!("error: {}", e), String::new()),
+        };
+
+        let previous = answers.insert((path.to_path_buf(), *part), answer.clone());
+        let change = match previous {
+            None => String::new(),
+            Some(previous) if previous == answer => " (unchanged)".to_string(),
+            Some(previous) => format!(" (changed from {})", previous),
+        };
+
+        println!(
+            "{} part {}: {}{}{}",
+            path.display(),
+            part,
+            answer,
+            change,
+            timing
+        );
+    }
+}
+
+fn fingerprint(path: &Path) -> Option<Fingerprint> {
+    let metadata = path.metadata().ok()?;
+    Some(Fingerprint {
+        modified: metadata.modified().ok(),
+        len: metadata.len(),
+    })
+}
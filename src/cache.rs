@@ -0,0 +1,150 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+use crate::answer::Answer;
+use crate::days::{Day, Part};
+use crate::input;
+
+// Caching is on whenever this names a directory, such as ./data/cache which is
+// already ignored by git
+pub const DIR_VAR: &str = "AOC_CACHE_DIR";
+
+// Answers from an older build may be wrong, so they are never reused once the
+// crate version changes
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+static ENABLED: RwLock<Option<Cache>> = RwLock::new(None);
+
+// Answers stored one file per day, part and input, named by the input's
+// hash, so a changed input is simply a miss
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    // anything unreadable is treated as not cached
+    pub fn get(&self, day: Day, part: Part, data: &str) -> Option<Answer> {
+        let json = fs::read_to_string(self.path(day, part, data)).ok()?;
+        serde_json::from_str(&json).ok()
+    }
+
+    pub fn put(&self, day: Day, part: Part, data: &str, answer: &Answer) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let json = serde_json::to_string(answer).expect("answer is serializable");
+        fs::write(self.path(day, part, data), json)
+    }
+
+    // Removes every cached answer, returning how many there were
+    pub fn clear(&self) -> io::Result<usize> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e),
+        };
+
+        let mut removed = 0;
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                fs::remove_file(path)?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    fn path(&self, day: Day, part: Part, data: &str) -> PathBuf {
+        self.dir.join(format!(
+            "day{:02}-part{}-{}-v{}.json",
+            day,
+            part,
+            input::hash(data),
+            VERSION
+        ))
+    }
+}
+
+// The cache set up by the environment, if any
+pub fn from_env() -> Option<Cache> {
+    std::env::var_os(DIR_VAR)
+        .filter(|dir| !dir.is_empty())
+        .map(Cache::new)
+}
+
+// Makes `crate::run` and the runner answer from `cache` where they can
+pub fn enable(cache: Cache) {
+    *ENABLED.write().expect("cache lock poisoned") = Some(cache);
+}
+
+pub fn disable() {
+    *ENABLED.write().expect("cache lock poisoned") = None;
+}
+
+pub fn enabled() -> Option<Cache> {
+    ENABLED.read().expect("cache lock poisoned").clone()
+}
+
+// The answer in the enabled cache, if there is one
+pub fn lookup(day: Day, part: Part, data: &str) -> Option<Answer> {
+    enabled()?.get(day, part, data)
+}
+
+// Saves an answer to the enabled cache, if there is one. A cache that can't be
+// written to just means solving again next time, so errors are ignored.
+pub fn store(day: Day, part: Part, data: &str, answer: &Answer) {
+    if let Some(cache) = enabled() {
+        let _ = cache.put(day, part, data, answer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_cache(name: &str) -> Cache {
+        let dir =
+            std::env::temp_dir().join(format!("aoc2015-cache-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        Cache::new(dir)
+    }
+
+    #[test]
+    fn get_put() {
+        let cache = temp_cache("get-put");
+        assert_eq!(cache.get(1, 1, "(()"), None);
+
+        cache.put(1, 1, "(()", &Answer::from(1)).unwrap();
+        cache.put(4, 1, "abc", &Answer::from("abc")).unwrap();
+
+        assert_eq!(cache.get(1, 1, "(()"), Some(Answer::from(1)));
+        assert_eq!(cache.get(4, 1, "abc"), Some(Answer::from("abc")));
+        assert_eq!(cache.get(1, 2, "(()"), None);
+        assert_eq!(cache.get(1, 1, "(()("), None);
+
+        fs::remove_dir_all(cache.dir()).unwrap();
+    }
+
+    #[test]
+    fn clear() {
+        let cache = temp_cache("clear");
+        assert_eq!(cache.clear().unwrap(), 0);
+
+        cache.put(1, 1, "(", &Answer::from(1)).unwrap();
+        cache.put(1, 2, "(", &Answer::from(1)).unwrap();
+        assert_eq!(cache.clear().unwrap(), 2);
+        assert_eq!(cache.get(1, 1, "("), None);
+
+        fs::remove_dir_all(cache.dir()).unwrap();
+    }
+}
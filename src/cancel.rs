@@ -0,0 +1,79 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::error::AocError;
+
+// Cooperative cancellation for long running solvers. Solvers call `check`
+// every so often and bail out with the error it returns. Clones share the
+// same cancelled flag.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+    timeout: Option<Duration>,
+    deadline: Option<Instant>,
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self {
+            timeout: Some(timeout),
+            ..Self::default()
+        }
+    }
+
+    // A copy of this token whose timeout, if any, counts from now
+    pub fn started(&self) -> Self {
+        Self {
+            cancelled: Arc::clone(&self.cancelled),
+            timeout: self.timeout,
+            deadline: self.timeout.map(|t| Instant::now() + t),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn check(&self) -> Result<(), AocError> {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return Err(AocError::Cancelled);
+        }
+        match (self.timeout, self.deadline) {
+            (Some(timeout), Some(deadline)) if Instant::now() >= deadline => {
+                Err(AocError::TimedOut(timeout))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancel_is_shared_between_clones() {
+        let token = CancelToken::new();
+        let started = token.started();
+        assert!(started.check().is_ok());
+
+        token.cancel();
+        assert!(matches!(started.check(), Err(AocError::Cancelled)));
+    }
+
+    #[test]
+    fn times_out_after_start() {
+        let token = CancelToken::with_timeout(Duration::ZERO);
+        // not started, so no deadline yet
+        assert!(token.check().is_ok());
+        assert!(matches!(
+            token.started().check(),
+            Err(AocError::TimedOut(_))
+        ));
+    }
+}
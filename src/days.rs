@@ -9,31 +9,30 @@ pub mod day08;
 pub mod day09;
 pub mod day10;
 
+use crate::solution::DynSolution;
+
 pub type Day = u8;
 pub type Part = u8;
 
-pub fn run_day_part(day: Day, part: Part, data: String) -> String {
-    match (day, part) {
-        (1, 1) => day01::part1(&data),
-        (1, 2) => day01::part2(&data),
-        (2, 1) => day02::part1(&data),
-        (2, 2) => day02::part2(&data),
-        (3, 1) => day03::part1(&data),
-        (3, 2) => day03::part2(&data),
-        (4, 1) => day04::part1(&data),
-        (4, 2) => day04::part2(&data),
-        (5, 1) => day05::part1(&data),
-        (5, 2) => day05::part2(&data),
-        (6, 1) => day06::part1(&data),
-        (6, 2) => day06::part2(&data),
-        (7, 1) => day07::part1(&data),
-        (7, 2) => day07::part2(&data),
-        (8, 1) => day08::part1(&data),
-        (8, 2) => day08::part2(&data),
-        (9, 1) => day09::part1(&data),
-        (9, 2) => day09::part2(&data),
-        (10, 1) => day10::part1(&data),
-        (10, 2) => day10::part2(&data),
-        (day_m, part_m) => todo!("day {} part {} not implemented", day_m, part_m),
-    }
+pub const PARTS: [Part; 2] = [1, 2];
+
+static SOLUTIONS: &[&dyn DynSolution] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+];
+
+pub fn solutions() -> &'static [&'static dyn DynSolution] {
+    SOLUTIONS
+}
+
+pub fn find(day: Day) -> Option<&'static dyn DynSolution> {
+    SOLUTIONS.iter().copied().find(|s| s.day() == day)
 }
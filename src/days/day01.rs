@@ -1,29 +1,77 @@
-type Floor = i32;
-type Instruction = char;
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::solution::{Context, Example, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Not Quite Lisp";
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, "(())", "0"),
+        Example::new(1, "()()", "0"),
+        Example::new(1, "(((", "3"),
+        Example::new(1, "(()(()(", "3"),
+        Example::new(1, "))(((((", "3"),
+        Example::new(1, "())", "-1"),
+        Example::new(1, "))(", "-1"),
+        Example::new(1, ")))", "-3"),
+        Example::new(1, ")())())", "-3"),
+        Example::new(2, ")", "1"),
+        Example::new(2, "()())", "5"),
+    ];
+
+    type Input = Vec<Instruction>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        let data = data.trim_end();
+        data.char_indices()
+            .map(|(i, c)| match c {
+                '(' => Ok(Instruction::Up),
+                ')' => Ok(Instruction::Down),
+                c => Err(AocError::parse(
+                    data,
+                    &data[i..],
+                    format!("unexpected character {:?}", c),
+                )),
+            })
+            .collect()
+    }
 
-pub fn part1(data: &str) -> String {
-    data.chars().fold(0, handle_instruction).to_string()
+    fn part1(instructions: &Self::Input, _ctx: &Context) -> Result<Answer, AocError> {
+        Ok(instructions.iter().fold(0, handle_instruction).into())
+    }
+
+    fn part2(instructions: &Self::Input, _ctx: &Context) -> Result<Answer, AocError> {
+        instructions
+            .iter()
+            .enumerate()
+            .try_fold(0, |current_floor, (i, instruction)| {
+                let new_floor = handle_instruction(current_floor, instruction);
+                if new_floor < 0 {
+                    Err(i + 1)
+                } else {
+                    Ok(new_floor)
+                }
+            })
+            .map_or_else(
+                |position| Ok(position.into()),
+                |_| Err(AocError::unsolvable("santa never enters the basement")),
+            )
+    }
 }
 
-pub fn part2(data: &str) -> String {
-    data.chars()
-        .enumerate()
-        .try_fold(0, |current_floor, (i, instruction)| {
-            let new_floor = handle_instruction(current_floor, instruction);
-            if new_floor < 0 {
-                Err(i + 1)
-            } else {
-                Ok(new_floor)
-            }
-        })
-        .unwrap_err()
-        .to_string()
+type Floor = i32;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Instruction {
+    Up,
+    Down,
 }
 
-fn handle_instruction(current_floor: Floor, instruction: Instruction) -> Floor {
+fn handle_instruction(current_floor: Floor, instruction: &Instruction) -> Floor {
     match instruction {
-        '(' => current_floor + 1,
-        ')' => current_floor - 1,
-        c => panic!("unexpected character {}", c),
+        Instruction::Up => current_floor + 1,
+        Instruction::Down => current_floor - 1,
     }
 }
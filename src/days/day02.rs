@@ -1,21 +1,53 @@
-pub fn part1(data: &str) -> String {
-    parser::parse(&data)
-        .iter()
-        .map(|present| present.wrapping_paper())
-        .sum::<Feet>()
-        .to_string()
-}
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::solution::{self, Context, Example, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "I Was Told There Would Be No Math";
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, "2x3x4", "58"),
+        Example::new(1, "1x1x10", "43"),
+        Example::new(2, "2x3x4", "34"),
+        Example::new(2, "1x1x10", "14"),
+    ];
+
+    type Input = Vec<Present>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        parser::parse(data)
+    }
 
-pub fn part2(data: &str) -> String {
-    parser::parse(&data)
-        .iter()
-        .map(|present| present.ribbon())
-        .sum::<Feet>()
-        .to_string()
+    fn parsed_json(data: &str) -> Result<Option<Value>, AocError> {
+        Ok(Some(solution::to_json(&Self::parse(data)?)))
+    }
+
+    fn part1(presents: &Self::Input, _ctx: &Context) -> Result<Answer, AocError> {
+        Ok(presents
+            .iter()
+            .map(|present| present.wrapping_paper())
+            .sum::<Feet>()
+            .into())
+    }
+
+    fn part2(presents: &Self::Input, _ctx: &Context) -> Result<Answer, AocError> {
+        Ok(presents
+            .iter()
+            .map(|present| present.ribbon())
+            .sum::<Feet>()
+            .into())
+    }
 }
 
 type Feet = u32;
 
+// length, width and height
+#[derive(Serialize)]
 pub struct Present(Feet, Feet, Feet);
 
 impl Present {
@@ -38,26 +70,23 @@ impl Present {
 
 mod parser {
     use super::Present;
+    use crate::error::AocError;
+    use crate::parsing;
     use nom::{
-        character::complete::char, character::complete::u32, combinator::map,
-        multi::separated_list1, IResult,
+        character::complete::char,
+        character::complete::u32,
+        combinator::map,
+        sequence::{preceded, tuple},
+        IResult,
     };
 
-    pub fn parse(s: &str) -> Vec<Present> {
-        let (_rest, dimensions) = presents(s).unwrap();
-        dimensions
-    }
-
-    fn presents(s: &str) -> IResult<&str, Vec<Present>> {
-        separated_list1(char('\n'), present)(s)
+    pub fn parse(s: &str) -> Result<Vec<Present>, AocError> {
+        parsing::parse_lines(s, present)
     }
 
     fn present(s: &str) -> IResult<&str, Present> {
-        let p = separated_list1(char('x'), u32);
-        let mut p = map(p, |dims| {
-            assert!(dims.len() == 3);
-            Present(dims[0], dims[1], dims[2])
-        });
+        let p = tuple((u32, preceded(char('x'), u32), preceded(char('x'), u32)));
+        let mut p = map(p, |(l, w, h)| Present(l, w, h));
         p(s)
     }
 }
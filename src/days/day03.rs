@@ -1,78 +1,68 @@
 use std::collections::HashSet;
 
-pub fn part1(data: &str) -> String {
-    let mut houses = Houses::new();
-    houses.visit(&Coord::origin());
-
-    parser::parse(data)
-        .iter()
-        .fold(Coord::origin(), |current_coord, dir| {
-            let new_coord = current_coord.translate(dir);
-            houses.visit(&new_coord);
-            new_coord
-        });
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::grid::{Dir, Point};
+use crate::solution::{Context, Example, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Perfectly Spherical Houses in a Vacuum";
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, ">", "2"),
+        Example::new(1, "^>v<", "4"),
+        Example::new(1, "^v^v^v^v^v", "2"),
+        Example::new(2, "^v", "3"),
+        Example::new(2, "^>v<", "3"),
+        Example::new(2, "^v^v^v^v^v", "11"),
+    ];
+
+    type Input = Vec<Dir>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        parser::parse(data)
+    }
 
-    houses.visited_count().to_string()
-}
+    fn part1(dirs: &Self::Input, _ctx: &Context) -> Result<Answer, AocError> {
+        let mut houses = Houses::new();
+        houses.visit(&Point::ORIGIN);
 
-pub fn part2(data: &str) -> String {
-    let mut houses = Houses::new();
-    houses.visit(&Coord::origin());
+        dirs.iter().fold(Point::ORIGIN, |current_point, dir| {
+            let new_point = current_point.step(*dir);
+            houses.visit(&new_point);
+            new_point
+        });
 
-    let workers = 2;
+        Ok(houses.visited_count().into())
+    }
 
-    parser::parse(data)
-        .chunks(workers)
-        .fold([Coord::origin()].repeat(workers), |coords, dirs| {
-            let new_coords: Vec<Coord> = coords
-                .iter()
-                .zip(dirs.iter())
-                .map(|(coord, dir)| coord.translate(dir))
-                .collect();
+    fn part2(dirs: &Self::Input, _ctx: &Context) -> Result<Answer, AocError> {
+        let mut houses = Houses::new();
+        houses.visit(&Point::ORIGIN);
 
-            new_coords.iter().for_each(|coord| houses.visit(&coord));
+        let workers = 2;
 
-            new_coords
-        });
+        dirs.chunks(workers)
+            .fold([Point::ORIGIN].repeat(workers), |points, dirs| {
+                let new_points: Vec<Point> = points
+                    .iter()
+                    .zip(dirs.iter())
+                    .map(|(point, dir)| point.step(*dir))
+                    .collect();
 
-    houses.visited_count().to_string()
-}
+                new_points.iter().for_each(|point| houses.visit(point));
 
-#[derive(Eq, Hash, PartialEq, Copy, Clone)]
-struct Coord {
-    x: i32,
-    y: i32,
-}
+                new_points
+            });
 
-impl Coord {
-    fn origin() -> Self {
-        Self { x: 0, y: 0 }
-    }
-
-    fn translate(self, dir: &Dir) -> Self {
-        match dir {
-            Dir::North => Self {
-                y: self.y - 1,
-                ..self
-            },
-            Dir::South => Self {
-                y: self.y + 1,
-                ..self
-            },
-            Dir::East => Self {
-                x: self.x + 1,
-                ..self
-            },
-            Dir::West => Self {
-                x: self.x - 1,
-                ..self
-            },
-        }
+        Ok(houses.visited_count().into())
     }
 }
 
 struct Houses {
-    visited: HashSet<Coord>,
+    visited: HashSet<Point>,
 }
 
 impl Houses {
@@ -82,8 +72,8 @@ impl Houses {
         }
     }
 
-    fn visit(&mut self, coord: &Coord) -> () {
-        self.visited.insert(*coord);
+    fn visit(&mut self, point: &Point) {
+        self.visited.insert(*point);
     }
 
     fn visited_count(&self) -> usize {
@@ -91,25 +81,14 @@ impl Houses {
     }
 }
 
-#[derive(Clone)]
-pub enum Dir {
-    North,
-    South,
-    East,
-    West,
-}
-
 mod parser {
-    use super::Dir;
+    use crate::error::AocError;
+    use crate::grid::Dir;
+    use crate::parsing;
     use nom::{branch::alt, character::complete::char, combinator::value, multi::many1, IResult};
 
-    pub fn parse(s: &str) -> Vec<Dir> {
-        let (_rest, ds) = dirs(s).unwrap();
-        ds
-    }
-
-    fn dirs(s: &str) -> IResult<&str, Vec<Dir>> {
-        many1(dir)(s)
+    pub fn parse(s: &str) -> Result<Vec<Dir>, AocError> {
+        parsing::parse_all(s, many1(dir))
     }
 
     fn dir(s: &str) -> IResult<&str, Dir> {
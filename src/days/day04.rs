@@ -1,34 +1,65 @@
-use md5;
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::solution::{Context, Example, Solution};
 
-pub fn part1(data: &str) -> String {
-    let data = data.trim();
+pub struct Day04;
 
-    let mut i = 0;
-    let byte3_bits = 0b11110000u8;
-    loop {
-        let padded = data.to_owned() + &i.to_string();
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "The Ideal Stocking Stuffer";
+    // millions of hashes each, so only run when asked for
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, "abcdef", "609043").slow(),
+        Example::new(1, "pqrstuv", "1048970").slow(),
+        Example::new(2, "abcdef", "6742839").slow(),
+        Example::new(2, "pqrstuv", "5714438").slow(),
+    ];
 
-        let result = md5::compute(padded);
+    type Input = String;
 
-        if &result[0..=1] == [0, 0] && &result[2] & byte3_bits == 0 {
-            return i.to_string();
-        }
-        i += 1;
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        Ok(data.trim().to_owned())
+    }
+
+    fn part1(key: &Self::Input, ctx: &Context) -> Result<Answer, AocError> {
+        let byte3_bits = 0b11110000u8;
+        let i = find_hash(key, ctx, |result| {
+            result[0..=1] == [0, 0] && result[2] & byte3_bits == 0
+        })?;
+        Ok(i.into())
+    }
+
+    fn part2(key: &Self::Input, ctx: &Context) -> Result<Answer, AocError> {
+        let i = find_hash(key, ctx, |result| result[0..=2] == [0, 0, 0])?;
+        Ok(i.into())
     }
 }
 
-pub fn part2(data: &str) -> String {
-    let data = data.trim();
+// how many hashes to try between checking for cancellation and reporting progress
+const CHECK_INTERVAL: u64 = 1 << 14;
+
+fn find_hash(
+    key: &str,
+    ctx: &Context,
+    is_match: impl Fn(&md5::Digest) -> bool,
+) -> Result<u64, AocError> {
+    ctx.progress.start("candidates", None);
 
     let mut i = 0;
     loop {
-        let padded = data.to_owned() + &i.to_string();
+        let padded = key.to_owned() + &i.to_string();
 
         let result = md5::compute(padded);
 
-        if &result[0..=2] == [0, 0, 0] {
-            return i.to_string();
+        if is_match(&result) {
+            tracing::debug!(key, i, hash = %format_args!("{:x}", result), "hash hit");
+            return Ok(i);
         }
         i += 1;
+
+        if i % CHECK_INTERVAL == 0 {
+            ctx.progress.inc(CHECK_INTERVAL);
+            ctx.check()?;
+        }
     }
 }
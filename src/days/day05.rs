@@ -1,21 +1,44 @@
 use std::collections::HashMap;
 
-pub fn part1(data: &str) -> String {
-    data.lines().filter(|s| is_nice(s)).count().to_string()
-}
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::solution::{Context, Example, Solution};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Doesn't He Have Intern-Elves For This?";
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, "ugknbfddgicrmopn", "1"),
+        Example::new(1, "aaa", "1"),
+        Example::new(1, "jchzalrnumimnmhp", "0"),
+        Example::new(1, "haegwjzuvuyypxyu", "0"),
+        Example::new(1, "dvszwmarrgswjxmb", "0"),
+        Example::new(2, "qjhvhtzxzqqjkmpb", "1"),
+        Example::new(2, "xxyxx", "1"),
+        Example::new(2, "uurcxstgmygtbstg", "0"),
+        Example::new(2, "ieodomkazucvgmuy", "0"),
+    ];
+
+    type Input = Vec<String>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        Ok(data.lines().map(|s| s.to_owned()).collect())
+    }
 
-pub fn part2(data: &str) -> String {
-    data.lines().filter(|s| is_nice2(s)).count().to_string()
+    fn part1(strings: &Self::Input, _ctx: &Context) -> Result<Answer, AocError> {
+        Ok(strings.iter().filter(|s| is_nice(s)).count().into())
+    }
+
+    fn part2(strings: &Self::Input, _ctx: &Context) -> Result<Answer, AocError> {
+        Ok(strings.iter().filter(|s| is_nice2(s)).count().into())
+    }
 }
 
-const VOWELS: [u8; 5] = ['a' as u8, 'e' as u8, 'i' as u8, 'o' as u8, 'u' as u8];
+const VOWELS: [u8; 5] = [b'a', b'e', b'i', b'o', b'u'];
 const EXPECTED_VOWELS: u8 = 3;
-const ILLEGAL_PAIRS: [&[u8]; 4] = [
-    &"ab".as_bytes(),
-    &"cd".as_bytes(),
-    &"pq".as_bytes(),
-    &"xy".as_bytes(),
-];
+const ILLEGAL_PAIRS: [&[u8]; 4] = [b"ab", b"cd", b"pq", b"xy"];
 
 fn is_nice(s: &str) -> bool {
     let mut vowel_count = 0;
@@ -40,7 +63,7 @@ fn is_nice(s: &str) -> bool {
             vowel_count += 1;
         }
     }
-    return vowel_count == EXPECTED_VOWELS && repeated;
+    vowel_count == EXPECTED_VOWELS && repeated
 }
 
 fn is_nice2(s: &str) -> bool {
@@ -73,7 +96,7 @@ fn is_nice2(s: &str) -> bool {
             return true;
         }
     }
-    return false;
+    false
 }
 
 #[cfg(test)]
@@ -1,113 +1,105 @@
-pub fn part1(data: &str) -> String {
-    let instructions = parser::parse(data);
-
-    let mut turned_on_count = 0u32;
-    for x in 0..=999 {
-        for y in 0..=999 {
-            let coord = Coord2(x, y);
-            let mut house = false;
-            for instruction in instructions.iter() {
-                if instruction.1.is_coord_contained(&coord) {
-                    house = match instruction.0 {
-                        Op::TurnOn => true,
-                        Op::TurnOff => false,
-                        Op::Toggle => !house,
-                    }
-                }
-            }
-            if house {
-                turned_on_count += 1;
-            }
-        }
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::grid::{Grid, Rect, UPoint};
+use crate::solution::{self, Context, Example, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Probably a Fire Hazard";
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, "turn on 0,0 through 999,999", "1000000"),
+        Example::new(1, "toggle 0,0 through 999,0", "1000"),
+        Example::new(
+            1,
+            "turn on 0,0 through 999,999\nturn off 499,499 through 500,500",
+            "999996",
+        ),
+        Example::new(2, "turn on 0,0 through 0,0", "1"),
+        Example::new(2, "toggle 0,0 through 999,999", "2000000"),
+    ];
+
+    type Input = Vec<Instruction>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        parser::parse(data)
     }
 
-    turned_on_count.to_string()
-}
+    fn parsed_json(data: &str) -> Result<Option<Value>, AocError> {
+        Ok(Some(solution::to_json(&Self::parse(data)?)))
+    }
 
-pub fn part2(data: &str) -> String {
-    let instructions = parser::parse(data);
-
-    let mut total_brightness = 0u32;
-    for x in 0..=999 {
-        for y in 0..=999 {
-            let coord = Coord2(x, y);
-            let mut house_brightness = 0u32;
-            for instruction in instructions.iter() {
-                if instruction.1.is_coord_contained(&coord) {
-                    match instruction.0 {
-                        Op::TurnOn => house_brightness += 1,
-                        Op::TurnOff => {
-                            if house_brightness > 0 {
-                                house_brightness -= 1
-                            }
-                        }
-                        Op::Toggle => house_brightness += 2,
-                    }
+    fn part1(instructions: &Self::Input, ctx: &Context) -> Result<Answer, AocError> {
+        ctx.progress
+            .start("instructions", Some(instructions.len() as u64));
+
+        let mut lights = Grid::new(SIZE, SIZE, false);
+        for Instruction(op, area) in instructions.iter() {
+            ctx.check()?;
+            lights.update_rect(area, |light| {
+                *light = match op {
+                    Op::TurnOn => true,
+                    Op::TurnOff => false,
+                    Op::Toggle => !*light,
                 }
-            }
-            total_brightness += house_brightness;
+            });
+            ctx.progress.inc(1);
         }
+
+        Ok(lights.values().filter(|light| **light).count().into())
     }
 
-    total_brightness.to_string()
+    fn part2(instructions: &Self::Input, ctx: &Context) -> Result<Answer, AocError> {
+        ctx.progress
+            .start("instructions", Some(instructions.len() as u64));
+
+        let mut lights = Grid::new(SIZE, SIZE, 0u32);
+        for Instruction(op, area) in instructions.iter() {
+            ctx.check()?;
+            lights.update_rect(area, |brightness| match op {
+                Op::TurnOn => *brightness += 1,
+                Op::TurnOff => *brightness = brightness.saturating_sub(1),
+                Op::Toggle => *brightness += 2,
+            });
+            ctx.progress.inc(1);
+        }
+
+        Ok(lights.values().sum::<u32>().into())
+    }
 }
 
-#[derive(Debug)]
-pub struct Instruction(Op, Area);
+// lights outside this are never touched
+const SIZE: usize = 1000;
+
+#[derive(Debug, Serialize)]
+pub struct Instruction(Op, Rect);
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
 enum Op {
     TurnOn,
     TurnOff,
     Toggle,
 }
 
-type Coord = u32;
-
-#[derive(Debug)]
-pub struct Coord2(Coord, Coord);
-impl Coord2 {
-    fn x(&self) -> Coord {
-        self.0
-    }
-
-    fn y(&self) -> Coord {
-        self.1
-    }
-}
-
-#[derive(Debug)]
-pub struct Area(Coord2, Coord2);
-
-impl Area {
-    fn is_coord_contained(&self, coord: &Coord2) -> bool {
-        coord.x() >= self.0.x()
-            && coord.x() <= self.1.x()
-            && coord.y() >= self.0.y()
-            && coord.y() <= self.1.y()
-    }
-}
-
 mod parser {
     use super::*;
+    use crate::parsing;
     use nom::{
         branch::alt,
         bytes::complete::tag,
         character::complete::{char, u32},
         combinator::{map, value},
-        multi::separated_list0,
         sequence::separated_pair,
         IResult,
     };
 
-    pub fn parse(s: &str) -> Vec<Instruction> {
-        let (rest, ins) = instructions(s).unwrap();
-        assert!(rest.is_empty());
-        ins
-    }
-
-    fn instructions(s: &str) -> IResult<&str, Vec<Instruction>> {
-        separated_list0(char('\n'), instruction)(s)
+    pub fn parse(s: &str) -> Result<Vec<Instruction>, AocError> {
+        parsing::parse_lines(s, instruction)
     }
 
     fn instruction(s: &str) -> IResult<&str, Instruction> {
@@ -131,13 +123,14 @@ mod parser {
         value(Op::Toggle, tag("toggle"))(s)
     }
 
-    fn area(s: &str) -> IResult<&str, Area> {
-        let p = separated_pair(coord, tag(" through "), coord);
-        map(p, |(c1, c2)| Area(c1, c2))(s)
+    fn area(s: &str) -> IResult<&str, Rect> {
+        let p = separated_pair(point, tag(" through "), point);
+        // kept as written, so a reversed range covers no lights
+        map(p, |(from, to)| Rect::new(from, to))(s)
     }
 
-    fn coord(s: &str) -> IResult<&str, Coord2> {
+    fn point(s: &str) -> IResult<&str, UPoint> {
         let p = separated_pair(u32, char(','), u32);
-        map(p, |(x, y)| Coord2(x, y))(s)
+        map(p, |(x, y)| UPoint::new(x as usize, y as usize))(s)
     }
 }
@@ -1,19 +1,120 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
-pub fn part1(data: &str) -> String {
-    let instructions = parser::parse(data);
-    let wire_connections = WireConnections::new(&instructions);
-    wire_connections.value("a").to_string()
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::solution::{self, Context, Solution};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Some Assembly Required";
+
+    type Input = Circuit;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        let instructions = parser::parse(data)?;
+        check_wires_connected(&instructions)?;
+        let wire_connections = WireConnections::new(&instructions);
+        Ok(Circuit {
+            instructions,
+            overrides: HashMap::new(),
+            wire_connections,
+        })
+    }
+
+    // each wire's gate, by wire name
+    fn parsed_json(data: &str) -> Result<Option<Value>, AocError> {
+        let circuit = Self::parse(data)?;
+        let gates: BTreeMap<&str, &Gate> = circuit
+            .instructions
+            .iter()
+            .map(|Instruction(gate, wire)| (wire.as_str(), gate))
+            .collect();
+        Ok(Some(solution::to_json(&gates)))
+    }
+
+    fn part1(circuit: &Self::Input, _ctx: &Context) -> Result<Answer, AocError> {
+        Ok(circuit.signal("a")?.into())
+    }
+
+    fn part2(circuit: &Self::Input, _ctx: &Context) -> Result<Answer, AocError> {
+        // resolved wires are memoized, so this reuses part 1's value of a
+        // when it has already been run against the same input
+        let a = circuit.signal("a")?;
+
+        let mut wire_connections_2 = WireConnections::new(&circuit.instructions);
+        wire_connections_2.override_value("b", a);
+        Ok(wire_connections_2.signal("a")?.into())
+    }
 }
 
-pub fn part2(data: &str) -> String {
-    let instructions = parser::parse(data);
-    let wire_connections = WireConnections::new(&instructions);
+pub struct Circuit {
+    instructions: Vec<Instruction>,
+    overrides: HashMap<WireLabel, u16>,
+    wire_connections: WireConnections,
+}
 
-    let mut wire_connections_2 = WireConnections::new(&instructions);
-    wire_connections_2.override_value("b", wire_connections.value("a"));
-    wire_connections_2.value("a").to_string()
+impl Circuit {
+    pub fn signal(&self, wire_label: &str) -> Result<u16, AocError> {
+        self.wire_connections.signal(wire_label)
+    }
+
+    pub fn wires(&self) -> Vec<&str> {
+        let mut wires: Vec<&str> = self.instructions.iter().map(|ins| ins.1.as_str()).collect();
+        wires.sort();
+        wires
+    }
+
+    // Forces a wire to carry `value` whatever its gate would give, until
+    // overrides are cleared. Drops any signals already resolved, as they may
+    // depend on the overridden wire.
+    pub fn override_wire(&mut self, wire_label: &str, value: u16) -> Result<(), AocError> {
+        if !self.wire_connections.connections.contains_key(wire_label) {
+            return Err(AocError::unsolvable(format!("no wire {}", wire_label)));
+        }
+        self.overrides.insert(wire_label.to_string(), value);
+        self.reconnect();
+        Ok(())
+    }
+
+    pub fn clear_overrides(&mut self) {
+        self.overrides.clear();
+        self.reconnect();
+    }
+
+    pub fn overrides(&self) -> &HashMap<WireLabel, u16> {
+        &self.overrides
+    }
+
+    fn reconnect(&mut self) {
+        self.wire_connections = WireConnections::new(&self.instructions);
+        for (wire_label, value) in self.overrides.iter() {
+            self.wire_connections.override_value(wire_label, *value);
+        }
+    }
+}
+
+fn check_wires_connected(instructions: &[Instruction]) -> Result<(), AocError> {
+    let wires: HashSet<&str> = instructions.iter().map(|ins| ins.1.as_str()).collect();
+
+    for ins in instructions.iter() {
+        for input in ins.0.inputs() {
+            if let Input::Unresolved(wl) = input {
+                if !wires.contains(wl.as_str()) {
+                    return Err(AocError::unsolvable(format!(
+                        "wire {} has no signal source",
+                        wl
+                    )));
+                }
+            }
+        }
+    }
+    Ok(())
 }
 
 struct WireConnections {
@@ -21,7 +122,7 @@ struct WireConnections {
 }
 
 impl WireConnections {
-    fn new(instructions: &Vec<Instruction>) -> Self {
+    fn new(instructions: &[Instruction]) -> Self {
         let mut connections = HashMap::new();
 
         for ins in instructions.iter() {
@@ -31,17 +132,27 @@ impl WireConnections {
         Self { connections }
     }
 
-    fn value(&self, wire_label: &str) -> u16 {
-        self.connections.get(wire_label).unwrap().value(self)
+    fn value(&self, wire_label: &str) -> Result<u16, AocError> {
+        self.connections
+            .get(wire_label)
+            .unwrap()
+            .value(wire_label, self)
     }
 
-    fn override_value(&mut self, wire_label: &str, value: u16) -> () {
+    fn signal(&self, wire_label: &str) -> Result<u16, AocError> {
+        if !self.connections.contains_key(wire_label) {
+            return Err(AocError::unsolvable(format!("no wire {}", wire_label)));
+        }
+        self.value(wire_label)
+    }
+
+    fn override_value(&mut self, wire_label: &str, value: u16) {
         self.connections
             .insert(wire_label.to_string(), Connection::new_with_value(value));
     }
 }
 
-type WireLabel = String;
+pub type WireLabel = String;
 
 #[derive(Debug)]
 struct Connection {
@@ -59,48 +170,50 @@ impl Connection {
         Self { value }
     }
 
-    fn value(&self, wire_connections: &WireConnections) -> u16 {
-        if !self.value.borrow().is_resolved() {
-            self.value.replace_with(|v| v.resolve(wire_connections));
+    // Resolves the gate on first use and remembers its signal. The wire is
+    // marked as resolving meanwhile, so a circuit that loops back to it is an
+    // error rather than endless recursion.
+    fn value(&self, wire_label: &str, wire_connections: &WireConnections) -> Result<u16, AocError> {
+        let gate = match self.value.replace(ConnectionState::Resolving) {
+            ConnectionState::Resolved(v) => {
+                self.value.replace(ConnectionState::Resolved(v));
+                return Ok(v);
+            }
+            ConnectionState::Resolving => {
+                return Err(AocError::unsolvable(format!(
+                    "wire {} depends on its own signal",
+                    wire_label
+                )));
+            }
+            ConnectionState::Unresolved(gate) => gate,
+        };
+
+        match gate.value(wire_connections) {
+            Ok(signal) => {
+                tracing::debug!(wire = wire_label, ?gate, signal, "resolved gate");
+                self.value.replace(ConnectionState::Resolved(signal));
+                Ok(signal)
+            }
+            Err(e) => {
+                self.value.replace(ConnectionState::Unresolved(gate));
+                Err(e)
+            }
         }
-
-        self.value.borrow().unwrap_value()
     }
 }
 
 #[derive(Debug)]
 enum ConnectionState {
     Unresolved(Gate),
+    Resolving,
     Resolved(u16),
 }
 
-impl ConnectionState {
-    fn is_resolved(&self) -> bool {
-        match self {
-            Self::Resolved(_) => true,
-            _ => false,
-        }
-    }
-
-    fn unwrap_value(&self) -> u16 {
-        match self {
-            Self::Unresolved(_) => panic!("connection value not resolved"),
-            Self::Resolved(v) => *v,
-        }
-    }
-
-    fn resolve(&self, wire_connections: &WireConnections) -> Self {
-        match self {
-            Self::Unresolved(i) => Self::Resolved(i.value(wire_connections)),
-            Self::Resolved(_) => panic!("already resolved"),
-        }
-    }
-}
-
 #[derive(Debug)]
 pub struct Instruction(Gate, WireLabel);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
 pub enum Gate {
     And(Input, Input),
     Or(Input, Input),
@@ -111,56 +224,73 @@ pub enum Gate {
 }
 
 impl Gate {
-    fn value(&self, wire_connections: &WireConnections) -> u16 {
+    fn value(&self, wire_connections: &WireConnections) -> Result<u16, AocError> {
+        Ok(match self {
+            Self::And(i1, i2) => i1.value(wire_connections)? & i2.value(wire_connections)?,
+            Self::Or(i1, i2) => i1.value(wire_connections)? | i2.value(wire_connections)?,
+            Self::LShift(i1, i2) => {
+                let shift = i2.value(wire_connections)?;
+                i1.value(wire_connections)?
+                    .checked_shl(u32::from(shift))
+                    .ok_or_else(|| shift_overflow(shift))?
+            }
+            Self::RShift(i1, i2) => {
+                let shift = i2.value(wire_connections)?;
+                i1.value(wire_connections)?
+                    .checked_shr(u32::from(shift))
+                    .ok_or_else(|| shift_overflow(shift))?
+            }
+            Self::Not(i) => !i.value(wire_connections)?,
+            Self::Direct(i) => i.value(wire_connections)?,
+        })
+    }
+
+    fn inputs(&self) -> Vec<&Input> {
         match self {
-            Self::And(i1, i2) => i1.value(wire_connections) & i2.value(wire_connections),
-            Self::Or(i1, i2) => i1.value(wire_connections) | i2.value(wire_connections),
-            Self::LShift(i1, i2) => i1.value(wire_connections) << i2.value(wire_connections),
-            Self::RShift(i1, i2) => i1.value(wire_connections) >> i2.value(wire_connections),
-            Self::Not(i) => !i.value(wire_connections),
-            Self::Direct(i) => i.value(wire_connections),
+            Self::And(i1, i2) | Self::Or(i1, i2) | Self::LShift(i1, i2) | Self::RShift(i1, i2) => {
+                vec![i1, i2]
+            }
+            Self::Not(i) | Self::Direct(i) => vec![i],
         }
     }
 }
 
-#[derive(Debug, Clone)]
+fn shift_overflow(shift: u16) -> AocError {
+    AocError::unsolvable(format!("shift by {} overflows a 16 bit signal", shift))
+}
+
+// a wire name or a fixed signal
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
 pub enum Input {
     Unresolved(WireLabel),
     Resolved(u16),
 }
 
-impl<'a> Input {
-    fn value(&self, wire_connections: &WireConnections) -> u16 {
+impl Input {
+    fn value(&self, wire_connections: &WireConnections) -> Result<u16, AocError> {
         match self {
             Self::Unresolved(wl) => wire_connections.value(wl),
-            Self::Resolved(v) => *v,
+            Self::Resolved(v) => Ok(*v),
         }
     }
 }
 
 mod parser {
-
     use nom::{
         branch::alt,
         bytes::complete::tag,
         character::complete::{alpha1, u16},
         combinator::{map, verify},
-        multi::separated_list0,
         sequence::{preceded, separated_pair},
         IResult,
     };
 
     use super::*;
+    use crate::parsing;
 
-    pub fn parse(s: &str) -> Vec<Instruction> {
-        let (rest, ins) = instructions(s).unwrap();
-        assert!(rest.is_empty());
-        ins
-    }
-
-    fn instructions(s: &str) -> IResult<&str, Vec<Instruction>> {
-        let mut p = separated_list0(tag("\n"), instruction);
-        p(s)
+    pub fn parse(s: &str) -> Result<Vec<Instruction>, AocError> {
+        parsing::parse_lines(s, instruction)
     }
 
     fn instruction(s: &str) -> IResult<&str, Instruction> {
@@ -229,7 +359,7 @@ mod parser {
     }
 
     fn resolved(s: &str) -> IResult<&str, Input> {
-        let mut p = map(u16, |value: u16| Input::Resolved(value));
+        let mut p = map(u16, Input::Resolved);
         p(s)
     }
 
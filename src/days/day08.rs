@@ -1,28 +1,67 @@
-pub fn part1(data: &str) -> String {
-    parser::parse(data)
-        .iter()
-        .map(|pair| pair.parsed_diff())
-        .sum::<usize>()
-        .to_string()
-}
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::solution::{self, Context, Example, Solution};
+
+pub struct Day08;
+
+const EXAMPLE: &str = r#"""
+"abc"
+"aaa\"aaa"
+"\x27""#;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Matchsticks";
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE, "12"),
+        Example::new(2, EXAMPLE, "19"),
+    ];
+
+    type Input = Vec<CodeParsedPair>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        parser::parse(data)
+    }
+
+    fn parsed_json(data: &str) -> Result<Option<Value>, AocError> {
+        Ok(Some(solution::to_json(&Self::parse(data)?)))
+    }
+
+    fn part1(pairs: &Self::Input, _ctx: &Context) -> Result<Answer, AocError> {
+        Ok(pairs
+            .iter()
+            .map(|pair| pair.parsed_diff())
+            .sum::<usize>()
+            .into())
+    }
 
-pub fn part2(data: &str) -> String {
-    parser::parse(data)
-        .iter()
-        .map(|pair| pair.encoded_diff())
-        .sum::<usize>()
-        .to_string()
+    fn part2(pairs: &Self::Input, _ctx: &Context) -> Result<Answer, AocError> {
+        Ok(pairs
+            .iter()
+            .map(|pair| pair.encoded_diff())
+            .sum::<usize>()
+            .into())
+    }
 }
 
 type CodeString = String;
 type ParsedString = Vec<char>;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct CodeParsedPair {
     code: CodeString,
+    #[serde(serialize_with = "serialize_parsed")]
     parsed: ParsedString,
 }
 
+// as a string rather than an array of one character strings
+fn serialize_parsed<S: Serializer>(parsed: &ParsedString, s: S) -> Result<S::Ok, S::Error> {
+    s.collect_str(&parsed.iter().collect::<String>())
+}
+
 impl CodeParsedPair {
     fn parsed_diff(&self) -> usize {
         self.code.len() - self.parsed.len()
@@ -49,26 +88,20 @@ impl CodeParsedPair {
 
 mod parser {
     use super::*;
+    use crate::parsing;
 
     use nom::{
         branch::alt,
         bytes::complete::{tag, take},
         character::complete::anychar,
         combinator::{consumed, map, map_opt, value, verify},
-        multi::{fold_many0, separated_list0},
+        multi::fold_many0,
         sequence::{delimited, preceded},
         IResult,
     };
 
-    pub fn parse(s: &str) -> Vec<CodeParsedPair> {
-        let (rest, code_parsed_pairs) = code_parsed_pairs(s).unwrap();
-        assert!(rest.is_empty());
-        code_parsed_pairs
-    }
-
-    fn code_parsed_pairs(s: &str) -> IResult<&str, Vec<CodeParsedPair>> {
-        let mut p = separated_list0(tag("\n"), code_parsed_pair);
-        p(s)
+    pub fn parse(s: &str) -> Result<Vec<CodeParsedPair>, AocError> {
+        parsing::parse_lines(s, code_parsed_pair)
     }
 
     fn code_parsed_pair(s: &str) -> IResult<&str, CodeParsedPair> {
@@ -99,7 +132,7 @@ mod parser {
     }
 
     fn lower_alpha(s: &str) -> IResult<&str, char> {
-        let mut p = verify(anychar, |c| c >= &'a' && c <= &'z');
+        let mut p = verify(anychar, |c| c.is_ascii_lowercase());
         p(s)
     }
 
@@ -131,7 +164,7 @@ mod tests {
     #[test]
     fn parses_escaped_backslash() {
         let code = r#""abc\\def""#;
-        let pairs = parser::parse(code);
+        let pairs = parser::parse(code).unwrap();
         assert_eq!(pairs.len(), 1);
         assert_eq!(pairs[0].code, code);
         assert_eq!(pairs[0].parsed, vec!['a', 'b', 'c', '\\', 'd', 'e', 'f']);
@@ -140,7 +173,7 @@ mod tests {
     #[test]
     fn parses_escaped_double_quote() {
         let code = r#""abc\"def""#;
-        let pairs = parser::parse(code);
+        let pairs = parser::parse(code).unwrap();
         assert_eq!(pairs.len(), 1);
         assert_eq!(pairs[0].code, code);
         assert_eq!(pairs[0].parsed, vec!['a', 'b', 'c', '\"', 'd', 'e', 'f']);
@@ -149,7 +182,7 @@ mod tests {
     #[test]
     fn parses_escaped_hexadecimal() {
         let code = r#""abc\x41def""#;
-        let pairs = parser::parse(code);
+        let pairs = parser::parse(code).unwrap();
         assert_eq!(pairs.len(), 1);
         assert_eq!(pairs[0].code, code);
         assert_eq!(pairs[0].parsed, vec!['a', 'b', 'c', 'A', 'd', 'e', 'f']);
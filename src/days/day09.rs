@@ -1,148 +1,83 @@
-use array2d::Array2D;
-use core::hash::Hash;
-use std::collections::HashMap;
-
-pub fn part1(data: &str) -> String {
-    let costs = build_costs(parser::parse(data));
-
-    (0..costs.row_len())
-        .fold(u32::MAX, |cheapest_found, from_id| {
-            min_visit_cost(
-                from_id,
-                costs.column_len(),
-                &vec![false; costs.column_len()],
-                0,
-                &costs,
-                cheapest_found,
-            )
-        })
-        .to_string()
-}
+use serde::Serialize;
+use serde_json::Value;
 
-pub fn part2(data: &str) -> String {
-    let costs = build_costs(parser::parse(data));
-
-    (0..costs.row_len())
-        .fold(u32::MIN, |costliest_found, from_id| {
-            max_visit_cost(
-                from_id,
-                costs.column_len(),
-                &vec![false; costs.column_len()],
-                0,
-                &costs,
-                costliest_found,
-            )
-        })
-        .to_string()
-}
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::graph::Graph;
+use crate::solution::{self, Context, Example, Solution};
 
-fn build_costs(connections: Vec<Connection>) -> Array2D<Cost> {
-    let mut registry: Registry<Location> = Registry::new();
-    for c in connections.iter() {
-        registry.add(c.from.to_owned());
-        registry.add(c.to.to_owned());
-    }
+pub struct Day09;
+
+const EXAMPLE: &str = "London to Dublin = 464
+London to Belfast = 518
+Dublin to Belfast = 141";
 
-    let location_count = registry.len();
-    let mut costs: Array2D<Cost> = Array2D::filled_with(0, location_count, location_count);
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "All in a Single Night";
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE, "605"),
+        Example::new(2, EXAMPLE, "982"),
+    ];
 
-    for c in connections.iter() {
-        let from_id = registry.get(&c.from);
-        let to_id = registry.get(&c.to);
-        // connections are bi-directional
-        costs[(from_id, to_id)] = c.cost;
-        costs[(to_id, from_id)] = c.cost;
+    type Input = Graph<Cost>;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        build_graph(parser::parse(data)?)
     }
-    costs
-}
 
-fn min_visit_cost(
-    from_id: usize,
-    location_count: usize,
-    visited: &Vec<bool>,
-    current_cost: Cost,
-    costs: &Array2D<Cost>,
-    cheapest_found: Cost,
-) -> u32 {
-    let mut cheapest_found = cheapest_found;
-    let mut visited = visited.clone();
-    visited[from_id] = true;
-
-    // terminal case - we've visited everything
-    if visited.iter().all(|v| *v) {
-        return current_cost;
+    // the connections as given, as the graph only has ids and a matrix
+    fn parsed_json(data: &str) -> Result<Option<Value>, AocError> {
+        Ok(Some(solution::to_json(&parser::parse(data)?)))
     }
 
-    for to_id in 0..location_count {
-        // continue if from/to are the same location, or we've already visited
-        if to_id == from_id || visited[to_id] {
-            continue;
-        }
+    fn part1(graph: &Self::Input, ctx: &Context) -> Result<Answer, AocError> {
+        ctx.progress.start("routes", None);
+        graph
+            .shortest_hamiltonian_path(ctx)?
+            .map(Answer::from)
+            .ok_or_else(no_route)
+    }
 
-        let connection_cost = costs[(from_id, to_id)];
-        // cost 0 means no connection
-        assert!(connection_cost != 0);
-
-        let to_cost = current_cost + connection_cost;
-        if to_cost < cheapest_found {
-            cheapest_found = min_visit_cost(
-                to_id,
-                location_count,
-                &visited,
-                to_cost,
-                costs,
-                cheapest_found,
-            )
-            .min(cheapest_found);
-        }
+    fn part2(graph: &Self::Input, ctx: &Context) -> Result<Answer, AocError> {
+        ctx.progress.start("routes", None);
+        graph
+            .longest_hamiltonian_path(ctx)?
+            .map(Answer::from)
+            .ok_or_else(no_route)
     }
-    // if we haven't visited
-    cheapest_found
 }
 
-fn max_visit_cost(
-    from_id: usize,
-    location_count: usize,
-    visited: &Vec<bool>,
-    current_cost: Cost,
-    costs: &Array2D<Cost>,
-    costliest_found: Cost,
-) -> u32 {
-    let mut costliest_found = costliest_found;
-    let mut visited = visited.clone();
-    visited[from_id] = true;
-
-    // terminal case - we've visited everything
-    if visited.iter().all(|v| *v) {
-        return current_cost;
+fn build_graph(connections: Vec<Connection>) -> Result<Graph<Cost>, AocError> {
+    // connections are bi-directional
+    let graph = Graph::undirected(
+        connections
+            .iter()
+            .map(|c| (c.from.as_str(), c.to.as_str(), c.cost)),
+    );
+    if graph.is_empty() {
+        return Err(AocError::unsolvable("no locations to visit"));
     }
 
-    for to_id in 0..location_count {
-        // continue if from/to are the same location, or we've already visited
-        if to_id == from_id || visited[to_id] {
-            continue;
+    for from_id in 0..graph.len() {
+        for to_id in 0..graph.len() {
+            if from_id != to_id && graph.weight(from_id, to_id).is_none() {
+                return Err(AocError::unsolvable(format!(
+                    "no distance given between {} and {}",
+                    graph.name(from_id),
+                    graph.name(to_id)
+                )));
+            }
         }
-
-        let connection_cost = costs[(from_id, to_id)];
-        // cost 0 means no connection
-        assert!(connection_cost != 0);
-
-        let to_cost = current_cost + connection_cost;
-        costliest_found = max_visit_cost(
-            to_id,
-            location_count,
-            &visited,
-            to_cost,
-            costs,
-            costliest_found,
-        )
-        .max(costliest_found);
     }
-    // if we haven't visited
-    costliest_found
+    Ok(graph)
 }
 
-#[derive(Debug)]
+fn no_route() -> AocError {
+    AocError::unsolvable("no route visits every location")
+}
+
+#[derive(Debug, Serialize)]
 pub struct Connection {
     from: Location,
     to: Location,
@@ -152,66 +87,26 @@ pub struct Connection {
 type Location = String;
 type Cost = u32;
 
-#[derive(Debug)]
-struct Registry<T> {
-    values: HashMap<T, usize>,
-    next_id: usize,
-}
-
-impl<T: Eq + Hash> Registry<T> {
-    fn new() -> Self {
-        Registry {
-            values: HashMap::new(),
-            next_id: 0,
-        }
-    }
-
-    fn add(&mut self, key: T) -> () {
-        if !self.values.contains_key(&key) {
-            self.values.insert(key, self.next_id);
-            self.next_id += 1;
-        }
-    }
-
-    fn get(&self, key: &T) -> usize {
-        *(self.values.get(key).unwrap())
-    }
-
-    fn len(&self) -> usize {
-        self.values.len()
-    }
-}
-
 mod parser {
     use nom::{
         bytes::complete::tag,
-        character::complete::{alpha1, u8},
+        character::complete::{alpha1, u32},
         combinator::map,
-        multi::separated_list0,
         sequence::separated_pair,
         IResult,
     };
 
     use super::*;
+    use crate::parsing;
 
-    pub fn parse(s: &str) -> Vec<Connection> {
-        let (rest, conns) = connections(s).unwrap();
-        assert!(rest.is_empty());
-        conns
-    }
-
-    fn connections(s: &str) -> IResult<&str, Vec<Connection>> {
-        separated_list0(tag("\n"), connection)(s)
+    pub fn parse(s: &str) -> Result<Vec<Connection>, AocError> {
+        parsing::parse_lines(s, connection)
     }
 
     fn connection(s: &str) -> IResult<&str, Connection> {
         map(
-            separated_pair(from_to, tag(" = "), u8),
-            |((from, to), cost)| Connection {
-                from,
-                to,
-                cost: cost as u32,
-            },
+            separated_pair(from_to, tag(" = "), u32),
+            |((from, to), cost)| Connection { from, to, cost },
         )(s)
     }
 
@@ -1,22 +1,44 @@
 use std::num::ParseIntError;
 use std::str::FromStr;
 
-pub fn part1(data: &str) -> String {
-    run(data, 40).to_string()
-}
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::solution::{Context, Solution};
 
-pub fn part2(data: &str) -> String {
-    run(data, 50).to_string()
-}
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Elves Look, Elves Say";
 
-fn run(data: &str, n: u32) -> u32 {
-    LookAndSay::from_str(data).unwrap().expand_n(n).len()
+    type Input = LookAndSay;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError> {
+        let data = data.trim_end();
+        if data.is_empty() {
+            return Err(AocError::parse(data, data, "expected digits"));
+        }
+        LookAndSay::from_str(data).map_err(|e| {
+            let rest = data
+                .find(|c: char| !c.is_ascii_digit())
+                .map_or("", |i| &data[i..]);
+            AocError::parse(data, rest, e.to_string())
+        })
+    }
+
+    fn part1(look_and_say: &Self::Input, ctx: &Context) -> Result<Answer, AocError> {
+        Ok(look_and_say.clone().expand_n(40, ctx)?.len().into())
+    }
+
+    fn part2(look_and_say: &Self::Input, ctx: &Context) -> Result<Answer, AocError> {
+        Ok(look_and_say.clone().expand_n(50, ctx)?.len().into())
+    }
 }
 
 type Count = u32;
 type N = u8;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 struct CountN {
     count: Count,
     n: N,
@@ -47,8 +69,8 @@ enum CountNAppendResult {
     Boundary(CountN, CountN),
 }
 
-#[derive(Debug, PartialEq)]
-struct LookAndSay {
+#[derive(Clone, Debug, PartialEq)]
+pub struct LookAndSay {
     count_ns: Vec<CountN>,
 }
 
@@ -71,12 +93,16 @@ impl LookAndSay {
         Self { count_ns }
     }
 
-    fn expand_n(self, n: u32) -> Self {
+    fn expand_n(self, n: u32, ctx: &Context) -> Result<Self, AocError> {
+        ctx.progress.start("rounds", Some(n.into()));
+
         let mut current = self;
         for _ in 0..n {
+            ctx.check()?;
             current = current.expand();
+            ctx.progress.inc(1);
         }
-        current
+        Ok(current)
     }
 
     fn expand(self) -> Self {
@@ -89,7 +115,7 @@ impl LookAndSay {
                 loop {
                     let digit = current_count % 10;
                     count_digits.push(CountN::unit(digit.try_into().unwrap()));
-                    current_count = current_count / 10;
+                    current_count /= 10;
                     if current_count == 0 {
                         break;
                     }
@@ -114,8 +140,7 @@ impl FromStr for LookAndSay {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let count_ns = s
             .chars()
-            .map(|c| c.to_string().parse().and_then(|n| Ok(CountN::unit(n))))
-            .into_iter()
+            .map(|c| c.to_string().parse().map(CountN::unit))
             .collect::<Result<Vec<CountN>, Self::Err>>()?;
 
         Ok(Self { count_ns }.collapse())
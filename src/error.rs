@@ -0,0 +1,168 @@
+use std::fmt;
+use std::time::Duration;
+
+use crate::days::{Day, Part};
+
+#[derive(Debug)]
+pub enum AocError {
+    Io(std::io::Error),
+    Parse {
+        line: usize,
+        column: usize,
+        snippet: String,
+        message: String,
+    },
+    Unsolvable(String),
+    NotImplemented {
+        day: Day,
+        part: Part,
+    },
+    TimedOut(Duration),
+    Cancelled,
+}
+
+const SNIPPET_LEN: usize = 30;
+
+impl AocError {
+    // `rest` is where parsing stopped. It can be any slice of `input`, such
+    // as the remainder of one line, or else must be a suffix of it. Line based
+    // parsers stop before the separating newline of a bad line, so skip over
+    // it to point at the line itself.
+    pub fn parse(input: &str, rest: &str, message: impl Into<String>) -> Self {
+        let rest = rest.trim_start_matches('\n');
+        let offset = offset_in(input, rest);
+        let consumed = &input[..offset];
+        let line = consumed.matches('\n').count() + 1;
+        let line_start = consumed.rfind('\n').map_or(0, |i| i + 1);
+        let column = consumed[line_start..].chars().count() + 1;
+        let snippet: String = input[offset..]
+            .lines()
+            .next()
+            .unwrap_or("")
+            .chars()
+            .take(SNIPPET_LEN)
+            .collect();
+
+        let mut message = message.into();
+        if snippet.is_empty() && input[offset..].trim().is_empty() {
+            message.push_str(" at end of input");
+        } else if snippet.is_empty() {
+            message.push_str(" at end of line");
+        }
+
+        Self::Parse {
+            line,
+            column,
+            snippet,
+            message,
+        }
+    }
+
+    pub fn unsolvable(message: impl Into<String>) -> Self {
+        Self::Unsolvable(message.into())
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Io(_) => "io",
+            Self::Parse { .. } => "parse",
+            Self::Unsolvable(_) => "unsolvable",
+            Self::NotImplemented { .. } => "not_implemented",
+            Self::TimedOut(_) => "timed_out",
+            Self::Cancelled => "cancelled",
+        }
+    }
+}
+
+// byte offset of `part` within `whole`, falling back to treating it as a suffix
+// when it doesn't point into `whole` at all
+fn offset_in(whole: &str, part: &str) -> usize {
+    let start = whole.as_ptr() as usize;
+    let at = part.as_ptr() as usize;
+    if at >= start && at + part.len() <= start + whole.len() {
+        at - start
+    } else {
+        whole.len() - part.len()
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{}", e),
+            Self::Parse {
+                line,
+                column,
+                snippet,
+                message,
+            } => {
+                write!(
+                    f,
+                    "parse error at line {}, column {}: {}",
+                    line, column, message
+                )?;
+                if !snippet.is_empty() {
+                    write!(f, " near {:?}", snippet)?;
+                }
+                Ok(())
+            }
+            Self::Unsolvable(message) => write!(f, "no solution: {}", message),
+            Self::NotImplemented { day, part } => {
+                write!(f, "day {} part {} not implemented", day, part)
+            }
+            Self::TimedOut(timeout) => write!(f, "timed out after {:?}", timeout),
+            Self::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for AocError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_error_position() {
+        let input = "1x2x3\n4x5y6\n7x8x9";
+        let err = AocError::parse(input, &input[9..], "expected 'x'");
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 2, column 4: expected 'x' near \"y6\""
+        );
+    }
+
+    #[test]
+    fn parse_error_within_line() {
+        let input = "1x2x3\n4x5\n7x8x9";
+        let line = input.lines().nth(1).unwrap();
+        let err = AocError::parse(input, &line[3..], "unexpected input");
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 2, column 4: unexpected input at end of line"
+        );
+    }
+
+    #[test]
+    fn parse_error_at_end_of_input() {
+        let input = "abc\n";
+        let err = AocError::parse(input, "", "incomplete input");
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 2, column 1: incomplete input at end of input"
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use crate::answer::Answer;
+use crate::answers::Outcome;
+use crate::days::Day;
+use crate::solution::{DynSolution, Example};
+
+#[derive(Debug)]
+pub struct ExampleCheck {
+    pub day: Day,
+    pub example: &'static Example,
+    pub outcome: Outcome,
+}
+
+impl ExampleCheck {
+    pub fn is_correct(&self) -> bool {
+        matches!(self.outcome, Outcome::Correct)
+    }
+}
+
+// Runs every example `solution` declares. Expected answers are compared as
+// they would be displayed, so "58" matches an answer of 58.
+pub fn check(solution: &dyn DynSolution, include_slow: bool) -> Vec<ExampleCheck> {
+    solution
+        .examples()
+        .iter()
+        .filter(|example| include_slow || !example.slow)
+        .map(|example| {
+            let result = solution.run(example.part, example.input);
+            ExampleCheck {
+                day: solution.day(),
+                example,
+                outcome: Outcome::compare(&Answer::from(example.expected), result),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,363 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::Add;
+
+use array2d::Array2D;
+
+use crate::error::AocError;
+use crate::solution::Context;
+
+// Hands out dense ids, in order of first appearance, for keys such as node names
+#[derive(Debug, Clone)]
+pub struct Registry<T> {
+    ids: HashMap<T, usize>,
+    keys: Vec<T>,
+}
+
+impl<T: Eq + Hash + Clone> Registry<T> {
+    pub fn new() -> Self {
+        Self {
+            ids: HashMap::new(),
+            keys: Vec::new(),
+        }
+    }
+
+    // the id of `key`, registering it if it's new
+    pub fn add(&mut self, key: T) -> usize {
+        if let Some(&id) = self.ids.get(&key) {
+            return id;
+        }
+        let id = self.keys.len();
+        self.ids.insert(key.clone(), id);
+        self.keys.push(key);
+        id
+    }
+
+    pub fn id(&self, key: &T) -> Option<usize> {
+        self.ids.get(key).copied()
+    }
+
+    pub fn key(&self, id: usize) -> &T {
+        &self.keys[id]
+    }
+
+    // in id order
+    pub fn keys(&self) -> &[T] {
+        &self.keys
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+impl<T: Eq + Hash + Clone> Default for Registry<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Named nodes joined by weighted edges, stored as an adjacency matrix. Nodes
+// are identified by their id in `nodes()`.
+#[derive(Debug, Clone)]
+pub struct Graph<W> {
+    nodes: Registry<String>,
+    weights: Array2D<Option<W>>,
+    directed: bool,
+}
+
+impl<W: Copy> Graph<W> {
+    // each edge works both ways
+    pub fn undirected<'a>(edges: impl IntoIterator<Item = (&'a str, &'a str, W)>) -> Self {
+        Self::build(edges, false)
+    }
+
+    // each edge goes from its first node to its second only
+    pub fn directed<'a>(edges: impl IntoIterator<Item = (&'a str, &'a str, W)>) -> Self {
+        Self::build(edges, true)
+    }
+
+    // A later edge between the same nodes replaces an earlier one
+    fn build<'a>(edges: impl IntoIterator<Item = (&'a str, &'a str, W)>, directed: bool) -> Self {
+        let mut nodes = Registry::new();
+        let edges: Vec<(usize, usize, W)> = edges
+            .into_iter()
+            .map(|(from, to, weight)| {
+                (
+                    nodes.add(from.to_string()),
+                    nodes.add(to.to_string()),
+                    weight,
+                )
+            })
+            .collect();
+
+        let mut weights = Array2D::filled_with(None, nodes.len(), nodes.len());
+        for (from, to, weight) in edges {
+            weights[(from, to)] = Some(weight);
+            if !directed {
+                weights[(to, from)] = Some(weight);
+            }
+        }
+
+        Self {
+            nodes,
+            weights,
+            directed,
+        }
+    }
+
+    pub fn nodes(&self) -> &Registry<String> {
+        &self.nodes
+    }
+
+    pub fn name(&self, id: usize) -> &str {
+        self.nodes.key(id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    // None when there's no edge from `from` to `to`
+    pub fn weight(&self, from: usize, to: usize) -> Option<W> {
+        self.weights[(from, to)]
+    }
+
+    // nodes reachable in one step from `from`, with the weight to each
+    pub fn edges_from(&self, from: usize) -> impl Iterator<Item = (usize, W)> + '_ {
+        (0..self.len()).filter_map(move |to| Some((to, self.weight(from, to)?)))
+    }
+}
+
+impl<W: Copy + Ord + Add<Output = W> + Default + Debug> Graph<W> {
+    // Total weight following `path` node to node, or None if an edge is missing
+    pub fn path_cost(&self, path: &[usize]) -> Option<W> {
+        path.windows(2).try_fold(W::default(), |cost, step| {
+            Some(cost + self.weight(step[0], step[1])?)
+        })
+    }
+
+    // As `path_cost`, but also returning from the last node to the first
+    pub fn cycle_cost(&self, path: &[usize]) -> Option<W> {
+        match (path.first(), path.last()) {
+            (Some(&first), Some(&last)) if path.len() > 1 => {
+                Some(self.path_cost(path)? + self.weight(last, first)?)
+            }
+            _ => self.path_cost(path),
+        }
+    }
+
+    // The cheapest path visiting every node exactly once, starting anywhere,
+    // or None if no such path exists. Each complete path counts one unit of
+    // progress.
+    pub fn shortest_hamiltonian_path(&self, ctx: &Context) -> Result<Option<W>, AocError> {
+        // a partial path can only get dearer when no weight is negative
+        let prune = self
+            .weights
+            .elements_row_major_iter()
+            .flatten()
+            .all(|&w| w >= W::default());
+        let mut search = Search {
+            graph: self,
+            visited: vec![false; self.len()],
+            best: None,
+            better: |a: W, b: W| a < b,
+            prune,
+            ctx,
+        };
+        search.search_from_each_node()?;
+        Ok(search.best)
+    }
+
+    // The dearest path visiting every node exactly once, starting anywhere,
+    // or None if no such path exists
+    pub fn longest_hamiltonian_path(&self, ctx: &Context) -> Result<Option<W>, AocError> {
+        let mut search = Search {
+            graph: self,
+            visited: vec![false; self.len()],
+            best: None,
+            better: |a: W, b: W| a > b,
+            prune: false,
+            ctx,
+        };
+        search.search_from_each_node()?;
+        Ok(search.best)
+    }
+}
+
+// Depth first search over every simple path, keeping the best complete one
+struct Search<'a, W, F> {
+    graph: &'a Graph<W>,
+    visited: Vec<bool>,
+    best: Option<W>,
+    better: F,
+    // give up on a partial path as soon as it's no better than the best found
+    prune: bool,
+    ctx: &'a Context,
+}
+
+impl<W, F> Search<'_, W, F>
+where
+    W: Copy + Ord + Add<Output = W> + Default + Debug,
+    F: Fn(W, W) -> bool,
+{
+    fn search_from_each_node(&mut self) -> Result<(), AocError> {
+        for start in 0..self.graph.len() {
+            self.visit(start, 1, W::default())?;
+        }
+        Ok(())
+    }
+
+    fn visit(&mut self, node: usize, visited_count: usize, cost: W) -> Result<(), AocError> {
+        self.ctx.check()?;
+        if visited_count == self.graph.len() {
+            self.ctx.progress.inc(1);
+            if self.best.is_none_or(|best| (self.better)(cost, best)) {
+                tracing::debug!(?cost, "new best path");
+                self.best = Some(cost);
+            }
+            return Ok(());
+        }
+
+        self.visited[node] = true;
+        for (to, weight) in self.graph.edges_from(node) {
+            if self.visited[to] {
+                continue;
+            }
+            let to_cost = cost + weight;
+            if self.prune && self.best.is_some_and(|best| !(self.better)(to_cost, best)) {
+                tracing::debug!(
+                    from = self.graph.name(node),
+                    to = self.graph.name(to),
+                    cost = ?to_cost,
+                    best = ?self.best,
+                    "pruned branch"
+                );
+                continue;
+            }
+            self.visit(to, visited_count + 1, to_cost)?;
+        }
+        self.visited[node] = false;
+        Ok(())
+    }
+}
+
+// Every ordering of 0..n, in lexicographic order
+pub fn permutations(n: usize) -> Permutations {
+    Permutations {
+        next: Some((0..n).collect()),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Permutations {
+    next: Option<Vec<usize>>,
+}
+
+impl Iterator for Permutations {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+        let mut next = current.clone();
+
+        // the rightmost element smaller than its successor, swapped with the
+        // rightmost element larger than it, then the tail after it reversed
+        if let Some(i) = (1..next.len()).rev().find(|&i| next[i - 1] < next[i]) {
+            let pivot = i - 1;
+            let j = (i..next.len())
+                .rev()
+                .find(|&j| next[j] > next[pivot])
+                .expect("the element after the pivot is larger");
+            next.swap(pivot, j);
+            next[i..].reverse();
+            self.next = Some(next);
+        }
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROUTES: [(&str, &str, u32); 3] = [
+        ("London", "Dublin", 464),
+        ("London", "Belfast", 518),
+        ("Dublin", "Belfast", 141),
+    ];
+
+    #[test]
+    fn registry_ids() {
+        let mut registry = Registry::new();
+        assert_eq!(registry.add("b"), 0);
+        assert_eq!(registry.add("a"), 1);
+        assert_eq!(registry.add("b"), 0);
+        assert_eq!(registry.id(&"a"), Some(1));
+        assert_eq!(registry.id(&"c"), None);
+        assert_eq!(registry.key(1), &"a");
+        assert_eq!(registry.keys(), ["b", "a"]);
+    }
+
+    #[test]
+    fn undirected_edges() {
+        let graph = Graph::undirected(ROUTES);
+        let london = graph.nodes().id(&"London".to_string()).unwrap();
+        let dublin = graph.nodes().id(&"Dublin".to_string()).unwrap();
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.weight(dublin, london), Some(464));
+        assert_eq!(graph.weight(london, london), None);
+        assert_eq!(graph.edges_from(london).count(), 2);
+    }
+
+    #[test]
+    fn directed_edges() {
+        let graph = Graph::directed([("a", "b", 1), ("b", "c", -2), ("c", "a", 4)]);
+        assert_eq!(graph.weight(0, 1), Some(1));
+        assert_eq!(graph.weight(1, 0), None);
+        assert_eq!(graph.path_cost(&[0, 1, 2]), Some(-1));
+        assert_eq!(graph.path_cost(&[2, 1]), None);
+        assert_eq!(graph.cycle_cost(&[0, 1, 2]), Some(3));
+    }
+
+    #[test]
+    fn hamiltonian_paths() {
+        let ctx = Context::default();
+        let graph = Graph::undirected(ROUTES);
+        assert_eq!(graph.shortest_hamiltonian_path(&ctx).unwrap(), Some(605));
+        assert_eq!(graph.longest_hamiltonian_path(&ctx).unwrap(), Some(982));
+
+        let graph = Graph::directed([("a", "b", 1), ("c", "b", 1)]);
+        assert_eq!(graph.shortest_hamiltonian_path(&ctx).unwrap(), None);
+    }
+
+    #[test]
+    fn permutations_in_order() {
+        assert_eq!(
+            permutations(3).collect::<Vec<_>>(),
+            [
+                [0, 1, 2],
+                [0, 2, 1],
+                [1, 0, 2],
+                [1, 2, 0],
+                [2, 0, 1],
+                [2, 1, 0]
+            ]
+        );
+        assert_eq!(permutations(5).count(), 120);
+        assert_eq!(permutations(0).collect::<Vec<_>>(), [Vec::<usize>::new()]);
+    }
+}
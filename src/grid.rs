@@ -0,0 +1,414 @@
+use std::ops::{Index, IndexMut};
+
+use serde::Serialize;
+
+// Screen style axes throughout: x grows east, y grows south, so north is -y
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Dir {
+    pub const ALL: [Dir; 4] = [Dir::North, Dir::East, Dir::South, Dir::West];
+
+    pub fn delta(self) -> (i64, i64) {
+        match self {
+            Self::North => (0, -1),
+            Self::South => (0, 1),
+            Self::East => (1, 0),
+            Self::West => (-1, 0),
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::South => Self::North,
+            Self::East => Self::West,
+            Self::West => Self::East,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Self::North => Self::West,
+            Self::West => Self::South,
+            Self::South => Self::East,
+            Self::East => Self::North,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        self.turn_left().opposite()
+    }
+}
+
+// offsets to all eight surrounding cells, diagonals included
+const AROUND: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+// A point on an unbounded plane
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Self = Self { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn step(self, dir: Dir) -> Self {
+        let (dx, dy) = dir.delta();
+        Self::new(self.x + dx, self.y + dy)
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    // the four points a step away in each direction
+    pub fn neighbors(self) -> impl Iterator<Item = Self> {
+        Dir::ALL.into_iter().map(move |dir| self.step(dir))
+    }
+
+    // the eight surrounding points, diagonals included
+    pub fn adjacent(self) -> impl Iterator<Item = Self> {
+        AROUND
+            .into_iter()
+            .map(move |(dx, dy)| Self::new(self.x + dx, self.y + dy))
+    }
+}
+
+// A point that can't go below zero, for indexing into a `Grid`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Serialize)]
+pub struct UPoint {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl UPoint {
+    pub const ORIGIN: Self = Self { x: 0, y: 0 };
+
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    // None if the step would go below zero
+    pub fn step(self, dir: Dir) -> Option<Self> {
+        Self::try_from(Point::from(self).step(dir)).ok()
+    }
+
+    pub fn neighbors(self) -> impl Iterator<Item = Self> {
+        Point::from(self)
+            .neighbors()
+            .filter_map(|p| Self::try_from(p).ok())
+    }
+
+    pub fn adjacent(self) -> impl Iterator<Item = Self> {
+        Point::from(self)
+            .adjacent()
+            .filter_map(|p| Self::try_from(p).ok())
+    }
+}
+
+impl From<UPoint> for Point {
+    fn from(p: UPoint) -> Self {
+        Self::new(p.x as i64, p.y as i64)
+    }
+}
+
+impl TryFrom<Point> for UPoint {
+    type Error = Point;
+
+    fn try_from(p: Point) -> Result<Self, Self::Error> {
+        match (usize::try_from(p.x), usize::try_from(p.y)) {
+            (Ok(x), Ok(y)) => Ok(Self::new(x, y)),
+            _ => Err(p),
+        }
+    }
+}
+
+// An axis aligned rectangle including both corners, so a single point is a
+// rectangle with an area of one. A `min` past `max` on either axis makes an
+// empty rectangle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub struct Rect {
+    pub min: UPoint,
+    pub max: UPoint,
+}
+
+impl Rect {
+    // corners as given, so an inverted pair is empty rather than swapped
+    pub const fn new(min: UPoint, max: UPoint) -> Self {
+        Self { min, max }
+    }
+
+    // from any two opposite corners
+    pub fn from_corners(a: UPoint, b: UPoint) -> Self {
+        Self {
+            min: UPoint::new(a.x.min(b.x), a.y.min(b.y)),
+            max: UPoint::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y
+    }
+
+    pub fn width(&self) -> usize {
+        if self.is_empty() {
+            0
+        } else {
+            self.max.x - self.min.x + 1
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        if self.is_empty() {
+            0
+        } else {
+            self.max.y - self.min.y + 1
+        }
+    }
+
+    pub fn area(&self) -> usize {
+        self.width() * self.height()
+    }
+
+    pub fn contains(&self, p: UPoint) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = UPoint::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y));
+        let max = UPoint::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y));
+        let rect = Self { min, max };
+        (!rect.is_empty()).then_some(rect)
+    }
+
+    // row by row
+    pub fn points(&self) -> impl Iterator<Item = UPoint> {
+        let Self { min, max } = *self;
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| UPoint::new(x, y)))
+    }
+}
+
+// A fixed size grid of cells stored row by row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(UPoint) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| UPoint::new(x, y)))
+            .map(&mut f)
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // None for an empty grid
+    pub fn bounds(&self) -> Option<Rect> {
+        (self.width > 0 && self.height > 0).then(|| Rect {
+            min: UPoint::ORIGIN,
+            max: UPoint::new(self.width - 1, self.height - 1),
+        })
+    }
+
+    pub fn contains(&self, p: UPoint) -> bool {
+        p.x < self.width && p.y < self.height
+    }
+
+    pub fn get(&self, p: UPoint) -> Option<&T> {
+        self.contains(p).then(|| &self.cells[self.index(p)])
+    }
+
+    pub fn get_mut(&mut self, p: UPoint) -> Option<&mut T> {
+        if self.contains(p) {
+            let i = self.index(p);
+            Some(&mut self.cells[i])
+        } else {
+            None
+        }
+    }
+
+    // neighbors of `p` that are inside the grid
+    pub fn neighbors(&self, p: UPoint) -> impl Iterator<Item = UPoint> + '_ {
+        p.neighbors().filter(|n| self.contains(*n))
+    }
+
+    // surrounding points of `p` that are inside the grid, diagonals included
+    pub fn adjacent(&self, p: UPoint) -> impl Iterator<Item = UPoint> + '_ {
+        p.adjacent().filter(|n| self.contains(*n))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (UPoint, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| (UPoint::new(i % width, i / width), cell))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    // Applies `f` to every cell in `rect`, skipping any part of it outside the grid
+    pub fn update_rect(&mut self, rect: &Rect, mut f: impl FnMut(&mut T)) {
+        let Some(rect) = self.bounds().and_then(|b| b.intersection(rect)) else {
+            return;
+        };
+        for y in rect.min.y..=rect.max.y {
+            let row = y * self.width;
+            for cell in &mut self.cells[row + rect.min.x..=row + rect.max.x] {
+                f(cell);
+            }
+        }
+    }
+
+    fn index(&self, p: UPoint) -> usize {
+        p.y * self.width + p.x
+    }
+}
+
+impl<T> Index<UPoint> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: UPoint) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{:?} outside {}x{} grid", p, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<UPoint> for Grid<T> {
+    fn index_mut(&mut self, p: UPoint) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{:?} outside {}x{} grid", p, width, height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_steps_and_neighbors() {
+        let p = Point::new(2, -3);
+        assert_eq!(p.step(Dir::North), Point::new(2, -4));
+        assert_eq!(p.step(Dir::East).step(Dir::West), p);
+        assert_eq!(p.neighbors().count(), 4);
+        assert_eq!(p.adjacent().count(), 8);
+        assert_eq!(Point::ORIGIN.manhattan_distance(p), 5);
+    }
+
+    #[test]
+    fn upoint_stays_non_negative() {
+        assert_eq!(UPoint::ORIGIN.step(Dir::North), None);
+        assert_eq!(UPoint::ORIGIN.step(Dir::South), Some(UPoint::new(0, 1)));
+        assert_eq!(UPoint::ORIGIN.neighbors().count(), 2);
+        assert_eq!(UPoint::ORIGIN.adjacent().count(), 3);
+    }
+
+    #[test]
+    fn dir_turns() {
+        assert_eq!(Dir::North.turn_right(), Dir::East);
+        assert_eq!(Dir::North.turn_left(), Dir::West);
+        assert_eq!(Dir::East.opposite(), Dir::West);
+    }
+
+    #[test]
+    fn rect_geometry() {
+        let a = Rect::from_corners(UPoint::new(3, 4), UPoint::new(0, 0));
+        assert_eq!(a.min, UPoint::new(0, 0));
+        assert_eq!(a.area(), 20);
+        assert!(a.contains(UPoint::new(3, 4)));
+        assert!(!a.contains(UPoint::new(4, 4)));
+
+        let b = Rect::new(UPoint::new(2, 2), UPoint::new(9, 9));
+        assert_eq!(
+            a.intersection(&b),
+            Some(Rect::new(UPoint::new(2, 2), UPoint::new(3, 4)))
+        );
+
+        let c = Rect::new(UPoint::new(5, 5), UPoint::new(6, 6));
+        assert_eq!(a.intersection(&c), None);
+        assert_eq!(c.points().count(), c.area());
+
+        let inverted = Rect::new(UPoint::new(3, 4), UPoint::new(0, 0));
+        assert!(inverted.is_empty());
+        assert_eq!(inverted.area(), 0);
+        assert_eq!(inverted.points().count(), 0);
+        assert!(!inverted.contains(UPoint::new(1, 1)));
+        assert_eq!(inverted.intersection(&b), None);
+
+        let edge = Rect::new(UPoint::new(usize::MAX, 0), UPoint::new(usize::MAX, 1));
+        assert_eq!((edge.width(), edge.height()), (1, 2));
+        assert_eq!(edge.area(), 2);
+    }
+
+    #[test]
+    fn grid_cells() {
+        let mut grid = Grid::new(3, 2, 0);
+        grid[UPoint::new(2, 1)] = 5;
+        grid.update_rect(&Rect::new(UPoint::new(1, 0), UPoint::new(9, 9)), |n| {
+            *n += 1
+        });
+
+        grid.update_rect(&Rect::new(UPoint::new(2, 1), UPoint::new(0, 0)), |n| {
+            *n += 10
+        });
+
+        assert_eq!(grid.get(UPoint::new(3, 0)), None);
+        assert_eq!(
+            grid.values().copied().collect::<Vec<_>>(),
+            [0, 1, 1, 0, 1, 6]
+        );
+        assert_eq!(grid.adjacent(UPoint::ORIGIN).count(), 3);
+        assert_eq!(
+            grid.iter().find(|(_, n)| **n == 6).map(|(p, _)| p),
+            Some(UPoint::new(2, 1))
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use std::fmt;
+use std::io::Read;
+use std::path::PathBuf;
+
+use crate::error::AocError;
+
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Text(String),
+    Reader(Box<dyn Read + Send>),
+}
+
+impl InputSource {
+    pub fn read(self) -> Result<String, AocError> {
+        match self {
+            Self::File(path) => Ok(crate::load_data(path)?),
+            Self::Stdin => read_all(std::io::stdin().lock()),
+            Self::Text(text) => Ok(text),
+            Self::Reader(reader) => read_all(reader),
+        }
+    }
+}
+
+// Identifies an input by content, independent of where it was read from
+pub fn hash(data: &str) -> String {
+    format!("{:x}", md5::compute(data))
+}
+
+fn read_all(mut reader: impl Read) -> Result<String, AocError> {
+    let mut data = String::new();
+    reader.read_to_string(&mut data)?;
+    Ok(data)
+}
+
+impl From<PathBuf> for InputSource {
+    fn from(path: PathBuf) -> Self {
+        Self::File(path)
+    }
+}
+
+impl From<String> for InputSource {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for InputSource {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_owned())
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "<stdin>"),
+            Self::Text(_) => write!(f, "<inline>"),
+            Self::Reader(_) => write!(f, "<reader>"),
+        }
+    }
+}
+
+impl fmt::Debug for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "InputSource({})", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_text_and_readers() {
+        assert_eq!(InputSource::from("(()").read().unwrap(), "(()");
+
+        let reader = InputSource::Reader(Box::new("^>v<".as_bytes()));
+        assert_eq!(reader.read().unwrap(), "^>v<");
+    }
+
+    #[test]
+    fn hashes_content() {
+        assert_eq!(hash("abcdef"), "e80b5017098950fc58aad83c8c14978e");
+    }
+}
@@ -1,18 +1,52 @@
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod cancel;
 pub mod days;
+pub mod error;
+pub mod examples;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod memory;
+pub mod parallel;
+pub mod parsing;
+pub mod progress;
+pub mod runner;
+pub mod solution;
 
 use std::io::Read;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use std::fs::File;
 
+use crate::answer::Answer;
 use crate::days::*;
+use crate::error::AocError;
+use crate::input::InputSource;
 
-pub fn run(day: Day, part: Part, path: PathBuf) -> std::io::Result<String> {
-    let data = load_data(path)?;
-    Ok(days::run_day_part(day, part, data))
+// Answers from the cache when one has been enabled with `cache::enable`
+pub fn run(day: Day, part: Part, input: impl Into<InputSource>) -> Result<Answer, AocError> {
+    let solution = days::find(day).ok_or(AocError::NotImplemented { day, part })?;
+    let data = input.into().read()?;
+
+    if let Some(answer) = cache::lookup(day, part, &data) {
+        return Ok(answer);
+    }
+
+    let answer = solution.run(part, &data)?;
+    cache::store(day, part, &data, &answer);
+    Ok(answer)
+}
+
+pub const DATA_DIR: &str = "./data";
+
+pub fn input_path(data_dir: &Path, day: Day) -> PathBuf {
+    data_dir.join(format!("day{:02}.txt", day))
 }
 
-fn load_data(file_path: PathBuf) -> Result<String, std::io::Error> {
+pub fn load_data(file_path: PathBuf) -> Result<String, std::io::Error> {
     let mut file = File::open(file_path)?;
     let mut data = String::new();
     file.read_to_string(&mut data)?;
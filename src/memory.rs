@@ -0,0 +1,136 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// Wraps the system allocator and counts bytes per thread, so days running in
+// parallel don't see each other's allocations. Does nothing until `enable` is
+// called. A binary opts in with
+//
+//     #[global_allocator]
+//     static ALLOCATOR: CountingAllocator = CountingAllocator;
+pub struct CountingAllocator;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static COUNTS: Counts = const {
+        Counts {
+            current: Cell::new(0),
+            peak: Cell::new(0),
+            total: Cell::new(0),
+        }
+    };
+}
+
+struct Counts {
+    // signed as memory freed on a different thread than it was allocated on
+    // takes this thread's count below where it started
+    current: Cell<i64>,
+    peak: Cell<i64>,
+    total: Cell<u64>,
+}
+
+impl Counts {
+    fn grow(&self, bytes: usize) {
+        let current = self.current.get() + bytes as i64;
+        self.current.set(current);
+        self.peak.set(self.peak.get().max(current));
+        self.total.set(self.total.get() + bytes as u64);
+    }
+
+    fn shrink(&self, bytes: usize) {
+        self.current.set(self.current.get() - bytes as i64);
+    }
+}
+
+fn record(f: impl FnOnce(&Counts)) {
+    if ENABLED.load(Ordering::Relaxed) {
+        // not available while the thread is being torn down
+        let _ = COUNTS.try_with(f);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(|counts| counts.grow(layout.size()));
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(|counts| counts.grow(layout.size()));
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(|counts| counts.shrink(layout.size()));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(|counts| {
+                if new_size > layout.size() {
+                    counts.grow(new_size - layout.size());
+                } else {
+                    counts.shrink(layout.size() - new_size);
+                }
+            });
+        }
+        new_ptr
+    }
+}
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    // most bytes held at once above what was held when measuring started
+    pub peak: u64,
+    // every byte allocated, including growth of reallocated blocks
+    pub total: u64,
+}
+
+impl MemoryUsage {
+    pub fn max(self, other: Self) -> Self {
+        Self {
+            peak: self.peak.max(other.peak),
+            total: self.total.max(other.total),
+        }
+    }
+}
+
+// Measures allocations made by `f` on the current thread. Returns no usage
+// unless counting has been enabled, which also means the counting allocator
+// is expected to be installed. Measurements don't nest: an inner one resets
+// the peak seen by an outer one.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<MemoryUsage>) {
+    if !is_enabled() {
+        return (f(), None);
+    }
+
+    let (start_current, start_total) = COUNTS.with(|counts| {
+        counts.peak.set(counts.current.get());
+        (counts.current.get(), counts.total.get())
+    });
+
+    let result = f();
+
+    let usage = COUNTS.with(|counts| MemoryUsage {
+        peak: (counts.peak.get() - start_current).max(0) as u64,
+        total: counts.total.get() - start_total,
+    });
+    (result, Some(usage))
+}
@@ -0,0 +1,120 @@
+use nom::error::ErrorKind;
+use nom::IResult;
+
+use crate::error::AocError;
+
+// Runs `parser` over all of `input`, which it must consume entirely. Trailing
+// whitespace, such as the final newline of a puzzle input, is ignored.
+pub fn parse_all<'a, O>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> Result<O, AocError> {
+    complete(input, input.trim_end(), &mut parser)
+}
+
+// Runs `line` over each line of `input`, each of which it must consume
+// entirely. Trailing whitespace on a line or at the end of the input is
+// ignored, and errors point at the line and column within the whole input.
+pub fn parse_lines<'a, O>(
+    input: &'a str,
+    mut line: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> Result<Vec<O>, AocError> {
+    input
+        .trim_end()
+        .lines()
+        .map(|l| complete(input, l.trim_end(), &mut line))
+        .collect()
+}
+
+// `input` is what positions are reported against, and must contain `part`
+fn complete<'a, O>(
+    input: &str,
+    part: &'a str,
+    parser: &mut impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> Result<O, AocError> {
+    let (rest, output) = parser(part).map_err(|e| nom_error(input, e))?;
+    if !rest.is_empty() {
+        return Err(AocError::parse(input, rest, "unexpected input"));
+    }
+    Ok(output)
+}
+
+// Converts a nom error on some slice of `input` into a parse error
+pub fn nom_error(input: &str, err: nom::Err<nom::error::Error<&str>>) -> AocError {
+    match err {
+        nom::Err::Incomplete(_) => AocError::parse(input, "", "incomplete input"),
+        nom::Err::Error(e) | nom::Err::Failure(e) => {
+            AocError::parse(input, e.input, describe(e.code))
+        }
+    }
+}
+
+// what the parser was after, in the terms of the puzzle input rather than nom's
+fn describe(kind: ErrorKind) -> &'static str {
+    match kind {
+        ErrorKind::Digit => "expected a number",
+        ErrorKind::Alpha | ErrorKind::AlphaNumeric => "expected a word",
+        ErrorKind::Space | ErrorKind::MultiSpace => "expected a space",
+        ErrorKind::Eof => "expected end of line",
+        ErrorKind::MapOpt | ErrorKind::MapRes | ErrorKind::Verify => "invalid value",
+        _ => "unexpected input",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::character::complete::{alpha1, char, u32};
+    use nom::multi::many1;
+    use nom::sequence::separated_pair;
+
+    use super::*;
+
+    fn pair(s: &str) -> IResult<&str, (u32, u32)> {
+        separated_pair(u32, char(','), u32)(s)
+    }
+
+    #[test]
+    fn lines_ignore_trailing_whitespace() {
+        assert_eq!(
+            parse_lines("1,2\n3,4 \n\n", pair).unwrap(),
+            [(1, 2), (3, 4)]
+        );
+        assert_eq!(parse_lines("", pair).unwrap(), []);
+    }
+
+    #[test]
+    fn lines_report_position_in_input() {
+        let err = parse_lines("1,2\n3,x\n5,6\n", pair).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 2, column 3: expected a number near \"x\""
+        );
+
+        let err = parse_lines("1,2\n3\n5,6\n", pair).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 2, column 2: unexpected input at end of line"
+        );
+    }
+
+    #[test]
+    fn lines_must_be_consumed() {
+        let err = parse_lines("1,2\n3,4,5", pair).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 2, column 4: unexpected input near \",5\""
+        );
+    }
+
+    #[test]
+    fn all_must_be_consumed() {
+        assert_eq!(parse_all("abc\n", alpha1).unwrap(), "abc");
+        assert_eq!(parse_all("aaa", many1(char('a'))).unwrap().len(), 3);
+
+        let err = parse_all("abc1\n", alpha1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 1, column 4: unexpected input near \"1\""
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+// Counters a solver bumps as it works through its search space. Cheap enough
+// to update from hot loops; whoever is displaying progress samples it with
+// `snapshot` on its own schedule. Clones share the same counters.
+#[derive(Debug, Clone, Default)]
+pub struct Progress {
+    state: Arc<ProgressState>,
+}
+
+#[derive(Debug, Default)]
+struct ProgressState {
+    done: AtomicU64,
+    total: AtomicU64,
+    labels: Mutex<Labels>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct Labels {
+    task: String,
+    unit: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    pub task: String,
+    pub unit: &'static str,
+    pub done: u64,
+    pub total: Option<u64>,
+}
+
+impl Progress {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Called by the runner before each part
+    pub fn begin(&self, task: impl Into<String>) {
+        self.state.done.store(0, Ordering::Relaxed);
+        self.state.total.store(0, Ordering::Relaxed);
+        *self.state.labels.lock().unwrap() = Labels {
+            task: task.into(),
+            unit: "",
+        };
+    }
+
+    // Called by solvers to say what they are counting, and how many of them
+    // there will be if known
+    pub fn start(&self, unit: &'static str, total: Option<u64>) {
+        self.state.labels.lock().unwrap().unit = unit;
+        self.state
+            .total
+            .store(total.unwrap_or(0), Ordering::Relaxed);
+    }
+
+    pub fn inc(&self, n: u64) {
+        self.state.done.fetch_add(n, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> Snapshot {
+        let labels = self.state.labels.lock().unwrap().clone();
+        let total = self.state.total.load(Ordering::Relaxed);
+
+        Snapshot {
+            task: labels.task,
+            unit: labels.unit,
+            done: self.state.done.load(Ordering::Relaxed),
+            total: (total > 0).then_some(total),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_shared_between_clones() {
+        let progress = Progress::new();
+        progress.begin("day 6 part 1");
+
+        let solver = progress.clone();
+        solver.start("lights", Some(1_000_000));
+        solver.inc(1000);
+        solver.inc(1000);
+
+        assert_eq!(
+            progress.snapshot(),
+            Snapshot {
+                task: "day 6 part 1".to_string(),
+                unit: "lights",
+                done: 2000,
+                total: Some(1_000_000),
+            }
+        );
+
+        progress.begin("day 6 part 2");
+        assert_eq!(progress.snapshot().done, 0);
+        assert_eq!(progress.snapshot().total, None);
+    }
+}
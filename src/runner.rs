@@ -0,0 +1,139 @@
+use std::time::{Duration, Instant};
+
+use crate::answer::Answer;
+use crate::cache;
+use crate::days::Part;
+use crate::error::AocError;
+use crate::memory::{self, MemoryUsage};
+use crate::solution::{Context, DynSolution};
+
+#[derive(Debug)]
+pub struct TimedRun {
+    pub part: Part,
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+    pub parse_memory: Option<MemoryUsage>,
+    pub solve_memory: Option<MemoryUsage>,
+    // answered from the cache, so nothing was parsed, solved or measured
+    pub cached: bool,
+}
+
+impl TimedRun {
+    fn cached(part: Part, answer: Answer) -> Self {
+        Self {
+            part,
+            answer,
+            parse_time: Duration::ZERO,
+            solve_time: Duration::ZERO,
+            parse_memory: None,
+            solve_memory: None,
+            cached: true,
+        }
+    }
+
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.solve_time
+    }
+}
+
+#[derive(Debug)]
+pub struct TimedParts {
+    pub parse_time: Duration,
+    pub parse_memory: Option<MemoryUsage>,
+    pub runs: Vec<Result<TimedRun, AocError>>,
+}
+
+impl TimedParts {
+    // true when the input was never parsed as every part came from the cache
+    pub fn is_cached(&self) -> bool {
+        self.runs
+            .iter()
+            .all(|run| run.as_ref().is_ok_and(|run| run.cached))
+    }
+}
+
+pub fn run_timed(
+    solution: &dyn DynSolution,
+    part: Part,
+    data: &str,
+    ctx: &Context,
+) -> Result<TimedRun, AocError> {
+    run_parts_timed(solution, &[part], data, ctx)?
+        .runs
+        .pop()
+        .expect("one part requested")
+}
+
+// Parses once and solves each part against the same input, in order, so a
+// later part can reuse anything an earlier one left behind. Every run shares
+// the one parse time. Fails as a whole only if parsing fails. Any timeout in
+// `ctx` applies to each part separately. Memory is only measured when
+// `memory::enable` has been called. Parts found in an enabled cache are not
+// solved, and if every part is found the input isn't parsed either.
+pub fn run_parts_timed(
+    solution: &dyn DynSolution,
+    parts: &[Part],
+    data: &str,
+    ctx: &Context,
+) -> Result<TimedParts, AocError> {
+    let cached: Vec<Option<Answer>> = parts
+        .iter()
+        .map(|&part| cache::lookup(solution.day(), part, data))
+        .collect();
+
+    if cached.iter().all(Option::is_some) {
+        let runs = parts
+            .iter()
+            .zip(cached)
+            .map(|(&part, answer)| Ok(TimedRun::cached(part, answer.expect("all cached"))))
+            .collect();
+        return Ok(TimedParts {
+            parse_time: Duration::ZERO,
+            parse_memory: None,
+            runs,
+        });
+    }
+
+    let start = Instant::now();
+    let (input, parse_memory) = memory::measure(|| solution.parse(data));
+    let input = input?;
+    let parse_time = start.elapsed();
+
+    let runs = parts
+        .iter()
+        .zip(cached)
+        .map(|(&part, cached)| {
+            if let Some(answer) = cached {
+                return Ok(TimedRun::cached(part, answer));
+            }
+
+            ctx.progress
+                .begin(format!("day {} part {}", solution.day(), part));
+
+            let start = Instant::now();
+            let (answer, solve_memory) =
+                memory::measure(|| solution.solve(input.as_ref(), part, &ctx.started()));
+            let answer = answer?;
+            let solve_time = start.elapsed();
+
+            cache::store(solution.day(), part, data, &answer);
+
+            Ok(TimedRun {
+                part,
+                answer,
+                parse_time,
+                solve_time,
+                parse_memory,
+                solve_memory,
+                cached: false,
+            })
+        })
+        .collect();
+
+    Ok(TimedParts {
+        parse_time,
+        parse_memory,
+        runs,
+    })
+}
@@ -0,0 +1,151 @@
+use std::any::Any;
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::answer::Answer;
+use crate::cancel::CancelToken;
+use crate::days::{Day, Part};
+use crate::error::AocError;
+use crate::progress::Progress;
+
+pub trait Solution {
+    const DAY: Day;
+    const TITLE: &'static str;
+    const EXAMPLES: &'static [Example] = &[];
+
+    type Input;
+
+    fn parse(data: &str) -> Result<Self::Input, AocError>;
+    fn part1(input: &Self::Input, ctx: &Context) -> Result<Answer, AocError>;
+    fn part2(input: &Self::Input, ctx: &Context) -> Result<Answer, AocError>;
+
+    // The parsed model as JSON, for `aoc parse` to dump. Still parses, to
+    // report any errors, for days with nothing to show.
+    fn parsed_json(data: &str) -> Result<Option<Value>, AocError> {
+        Self::parse(data).map(|_| None)
+    }
+
+    fn solve_part1(data: &str) -> Result<Answer, AocError> {
+        Self::part1(&Self::parse(data)?, &Context::default())
+    }
+
+    fn solve_part2(data: &str) -> Result<Answer, AocError> {
+        Self::part2(&Self::parse(data)?, &Context::default())
+    }
+}
+
+// For `parsed_json`, as every model built by a parser can be serialized
+pub fn to_json(model: &impl Serialize) -> Value {
+    serde_json::to_value(model).expect("parsed model is serializable")
+}
+
+// A worked example from the puzzle text, checked by `aoc examples` and the
+// examples test
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Example {
+    pub part: Part,
+    pub input: &'static str,
+    pub expected: &'static str,
+    // too slow to run on every `cargo test`
+    pub slow: bool,
+}
+
+impl Example {
+    pub const fn new(part: Part, input: &'static str, expected: &'static str) -> Self {
+        Self {
+            part,
+            input,
+            expected,
+            slow: false,
+        }
+    }
+
+    pub const fn slow(self) -> Self {
+        Self { slow: true, ..self }
+    }
+}
+
+// Passed to every part so long running solvers can be stopped by the runner
+// and report how far through they are
+#[derive(Debug, Clone, Default)]
+pub struct Context {
+    pub cancel: CancelToken,
+    pub progress: Progress,
+}
+
+impl Context {
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self {
+            cancel: CancelToken::with_timeout(timeout),
+            ..Self::default()
+        }
+    }
+
+    pub fn started(&self) -> Self {
+        Self {
+            cancel: self.cancel.started(),
+            progress: self.progress.clone(),
+        }
+    }
+
+    pub fn check(&self) -> Result<(), AocError> {
+        self.cancel.check()
+    }
+}
+
+// object safe view of a `Solution`, so all days can live in one registry
+pub trait DynSolution: Sync {
+    fn day(&self) -> Day;
+    fn title(&self) -> &'static str;
+    fn examples(&self) -> &'static [Example];
+    fn parse(&self, data: &str) -> Result<Box<dyn Any>, AocError>;
+    fn solve(&self, input: &dyn Any, part: Part, ctx: &Context) -> Result<Answer, AocError>;
+    fn parsed_json(&self, data: &str) -> Result<Option<Value>, AocError>;
+
+    fn run(&self, part: Part, data: &str) -> Result<Answer, AocError> {
+        self.solve(self.parse(data)?.as_ref(), part, &Context::default())
+    }
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn day(&self) -> Day {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        S::EXAMPLES
+    }
+
+    fn parse(&self, data: &str) -> Result<Box<dyn Any>, AocError> {
+        let _span = tracing::info_span!("parse", day = S::DAY).entered();
+        Ok(Box::new(S::parse(data)?))
+    }
+
+    fn solve(&self, input: &dyn Any, part: Part, ctx: &Context) -> Result<Answer, AocError> {
+        let _span = tracing::info_span!("solve", day = S::DAY, part).entered();
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input parsed by a different solution");
+
+        match part {
+            1 => S::part1(input, ctx),
+            2 => S::part2(input, ctx),
+            _ => Err(AocError::NotImplemented { day: S::DAY, part }),
+        }
+    }
+
+    fn parsed_json(&self, data: &str) -> Result<Option<Value>, AocError> {
+        let _span = tracing::info_span!("parse", day = S::DAY).entered();
+        S::parsed_json(data)
+    }
+}
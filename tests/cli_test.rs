@@ -0,0 +1,53 @@
+use std::process::{Command, Output};
+
+fn aoc(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+// a usage error from clap, rather than a panic
+fn assert_usage_error(args: &[&str]) {
+    let output = aoc(args);
+    assert_eq!(output.status.code(), Some(2), "{:?}", args);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.starts_with("error: invalid value"), "{}", stderr);
+}
+
+#[test]
+fn rejects_bad_time_budgets() {
+    for budget in ["-1", "0", "nan", "inf", "1e30"] {
+        let arg = format!("--time-budget={}", budget);
+        assert_usage_error(&["bench", "-d", "1", "-p", "1", "-i", "(", &arg]);
+    }
+}
+
+#[test]
+fn rejects_bad_timeouts() {
+    for timeout in ["-1", "0", "nan", "inf", "1e30"] {
+        let arg = format!("--timeout={}", timeout);
+        assert_usage_error(&["-d", "1", "-p", "1", "-i", "(", &arg]);
+    }
+}
+
+#[test]
+fn global_flags_before_subcommand() {
+    for flags in [
+        &["--output", "json"][..],
+        &["-t", "5"],
+        &["-m"],
+        &["--no-cache"],
+        &["-v"],
+    ] {
+        let mut args = flags.to_vec();
+        args.extend(["examples", "-d", "2"]);
+        let output = aoc(&args);
+        assert!(
+            output.status.success(),
+            "{:?}: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
@@ -1,20 +1,40 @@
-use aoc2015::days::day01::{part1, part2};
+use aoc2015::answer::Answer;
+use aoc2015::days::day01::Day01;
+use aoc2015::error::AocError;
+use aoc2015::solution::Solution;
 
 #[test]
 fn part1_example_data() {
-    assert_eq!(part1("(())"), "0");
-    assert_eq!(part1("()()"), "0");
-    assert_eq!(part1("((("), "3");
-    assert_eq!(part1("(()(()("), "3");
-    assert_eq!(part1("))((((("), "3");
-    assert_eq!(part1("())"), "-1");
-    assert_eq!(part1("))("), "-1");
-    assert_eq!(part1(")))"), "-3");
-    assert_eq!(part1(")())())"), "-3");
+    assert_eq!(Day01::solve_part1("(())").unwrap(), Answer::from(0));
+    assert_eq!(Day01::solve_part1("()()").unwrap(), Answer::from(0));
+    assert_eq!(Day01::solve_part1("(((").unwrap(), Answer::from(3));
+    assert_eq!(Day01::solve_part1("(()(()(").unwrap(), Answer::from(3));
+    assert_eq!(Day01::solve_part1("))(((((").unwrap(), Answer::from(3));
+    assert_eq!(Day01::solve_part1("())").unwrap(), Answer::from(-1));
+    assert_eq!(Day01::solve_part1("))(").unwrap(), Answer::from(-1));
+    assert_eq!(Day01::solve_part1(")))").unwrap(), Answer::from(-3));
+    assert_eq!(Day01::solve_part1(")())())").unwrap(), Answer::from(-3));
 }
 
 #[test]
 fn part2_example_data() {
-    assert_eq!(part2(")"), "1");
-    assert_eq!(part2("()())"), "5");
+    assert_eq!(Day01::solve_part2(")").unwrap(), Answer::from(1));
+    assert_eq!(Day01::solve_part2("()())").unwrap(), Answer::from(5));
+}
+
+#[test]
+fn rejects_unexpected_characters() {
+    let err = Day01::solve_part1("(()x)").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "parse error at line 1, column 4: unexpected character 'x' near \"x)\""
+    );
+}
+
+#[test]
+fn part2_never_reaching_basement_is_unsolvable() {
+    assert!(matches!(
+        Day01::solve_part2("(()"),
+        Err(AocError::Unsolvable(_))
+    ));
 }
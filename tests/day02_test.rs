@@ -1,13 +1,32 @@
-use aoc2015::days::day02::{part1, part2};
+use aoc2015::answer::Answer;
+use aoc2015::days::day02::Day02;
+use aoc2015::solution::Solution;
 
 #[test]
 fn part1_example_data() {
-    assert_eq!(part1("2x3x4"), "58");
-    assert_eq!(part1("1x1x10"), "43");
+    assert_eq!(Day02::solve_part1("2x3x4").unwrap(), Answer::from(58));
+    assert_eq!(Day02::solve_part1("1x1x10").unwrap(), Answer::from(43));
 }
 
 #[test]
 fn part2_example_data() {
-    assert_eq!(part2("2x3x4"), "34");
-    assert_eq!(part2("1x1x10"), "14");
+    assert_eq!(Day02::solve_part2("2x3x4").unwrap(), Answer::from(34));
+    assert_eq!(Day02::solve_part2("1x1x10").unwrap(), Answer::from(14));
+}
+
+#[test]
+fn reports_malformed_present() {
+    let err = Day02::solve_part1("2x3x4\n1x1\n1x1x10").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "parse error at line 2, column 4: unexpected input at end of line"
+    );
+}
+
+#[test]
+fn accepts_trailing_newline() {
+    assert_eq!(
+        Day02::solve_part1("2x3x4\n1x1x10\n").unwrap(),
+        Answer::from(101)
+    );
 }
@@ -1,15 +1,17 @@
-use aoc2015::days::day03::{part1, part2};
+use aoc2015::answer::Answer;
+use aoc2015::days::day03::Day03;
+use aoc2015::solution::Solution;
 
 #[test]
 fn part1_example_data() {
-    assert_eq!(part1(">"), "2");
-    assert_eq!(part1("^>v<"), "4");
-    assert_eq!(part1("^v^v^v^v^v"), "2");
+    assert_eq!(Day03::solve_part1(">").unwrap(), Answer::from(2));
+    assert_eq!(Day03::solve_part1("^>v<").unwrap(), Answer::from(4));
+    assert_eq!(Day03::solve_part1("^v^v^v^v^v").unwrap(), Answer::from(2));
 }
 
 #[test]
 fn part2_example_data() {
-    assert_eq!(part2("^v"), "3");
-    assert_eq!(part2("^>v<"), "3");
-    assert_eq!(part2("^v^v^v^v^v"), "11");
+    assert_eq!(Day03::solve_part2("^v").unwrap(), Answer::from(3));
+    assert_eq!(Day03::solve_part2("^>v<").unwrap(), Answer::from(3));
+    assert_eq!(Day03::solve_part2("^v^v^v^v^v").unwrap(), Answer::from(11));
 }
@@ -1,16 +1,36 @@
-use aoc2015::days::day04::{part1, part2};
+use aoc2015::answer::Answer;
+use aoc2015::days::day04::Day04;
+use aoc2015::error::AocError;
+use aoc2015::solution::{Context, Solution};
+use std::time::Duration;
 
 // these are slow. ignore by default
 #[ignore]
 #[test]
 fn part1_example_data() {
-    assert_eq!(part1("abcdef"), "609043");
-    assert_eq!(part1("pqrstuv"), "1048970");
+    assert_eq!(Day04::solve_part1("abcdef").unwrap(), Answer::from(609043));
+    assert_eq!(
+        Day04::solve_part1("pqrstuv").unwrap(),
+        Answer::from(1048970)
+    );
 }
 
 #[ignore]
 #[test]
 fn part2_example_data() {
-    assert_eq!(part2("abcdef"), "6742839");
-    assert_eq!(part2("pqrstuv"), "5714438");
+    assert_eq!(Day04::solve_part2("abcdef").unwrap(), Answer::from(6742839));
+    assert_eq!(
+        Day04::solve_part2("pqrstuv").unwrap(),
+        Answer::from(5714438)
+    );
+}
+
+#[test]
+fn part1_gives_up_after_timeout() {
+    let key = Day04::parse("abcdef").unwrap();
+    let ctx = Context::with_timeout(Duration::ZERO).started();
+    assert!(matches!(
+        Day04::part1(&key, &ctx),
+        Err(AocError::TimedOut(_))
+    ));
 }
@@ -0,0 +1,10 @@
+use aoc2015::answer::Answer;
+use aoc2015::days::day06::Day06;
+use aoc2015::solution::Solution;
+
+#[test]
+fn reversed_range_lights_nothing() {
+    let data = "turn on 5,5 through 0,0\ntoggle 0,0 through 0,0\n";
+    assert_eq!(Day06::solve_part1(data).unwrap(), Answer::from(1));
+    assert_eq!(Day06::solve_part2(data).unwrap(), Answer::from(2));
+}
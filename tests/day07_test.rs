@@ -0,0 +1,68 @@
+use aoc2015::days::day07::Day07;
+use aoc2015::solution::Solution;
+
+const EXAMPLE: &str = "123 -> x
+456 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i";
+
+#[test]
+fn signal_example_data() {
+    let circuit = Day07::parse(EXAMPLE).unwrap();
+    assert_eq!(circuit.signal("d").unwrap(), 72);
+    assert_eq!(circuit.signal("e").unwrap(), 507);
+    assert_eq!(circuit.signal("f").unwrap(), 492);
+    assert_eq!(circuit.signal("g").unwrap(), 114);
+    assert_eq!(circuit.signal("h").unwrap(), 65412);
+    assert_eq!(circuit.signal("i").unwrap(), 65079);
+    assert_eq!(circuit.signal("x").unwrap(), 123);
+    assert_eq!(circuit.signal("y").unwrap(), 456);
+}
+
+#[test]
+fn override_wire() {
+    let mut circuit = Day07::parse(EXAMPLE).unwrap();
+    assert_eq!(circuit.signal("d").unwrap(), 72);
+
+    circuit.override_wire("x", 0xffff).unwrap();
+    assert_eq!(circuit.signal("d").unwrap(), 456);
+    assert_eq!(circuit.signal("h").unwrap(), 0);
+
+    circuit.clear_overrides();
+    assert_eq!(circuit.signal("d").unwrap(), 72);
+
+    assert!(circuit.override_wire("z", 1).is_err());
+}
+
+#[test]
+fn rejects_cyclic_circuit() {
+    let err = Day07::solve_part1("b -> a\na -> b").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "no solution: wire a depends on its own signal"
+    );
+
+    // the failed resolution doesn't leave wires stuck mid way
+    let circuit = Day07::parse("b -> a\na -> b\n1 -> c").unwrap();
+    assert!(circuit.signal("b").is_err());
+    assert!(circuit.signal("b").is_err());
+    assert_eq!(circuit.signal("c").unwrap(), 1);
+}
+
+#[test]
+fn rejects_overflowing_shifts() {
+    let err = Day07::solve_part1("1 LSHIFT 20 -> a").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "no solution: shift by 20 overflows a 16 bit signal"
+    );
+    assert!(Day07::solve_part1("16 -> b\n1 RSHIFT b -> a").is_err());
+    assert_eq!(
+        Day07::solve_part1("1 LSHIFT 15 -> a").unwrap().to_string(),
+        "32768"
+    );
+}
@@ -1,4 +1,6 @@
-use aoc2015::days::day08::{part1, part2};
+use aoc2015::answer::Answer;
+use aoc2015::days::day08::Day08;
+use aoc2015::solution::Solution;
 
 #[test]
 fn part1_example_data() {
@@ -6,11 +8,5 @@ fn part1_example_data() {
 "abc"
 "aaa\\aaa"
 "\x27""#;
-    assert_eq!(part1(data), "12");
+    assert_eq!(Day08::solve_part1(data).unwrap(), Answer::from(12));
 }
-
-// #[test]
-// fn part2_example_data() {
-//     assert_eq!(part2("abcdef"), "6742839");
-//     assert_eq!(part2("pqrstuv"), "5714438");
-// }
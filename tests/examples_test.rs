@@ -0,0 +1,24 @@
+use aoc2015::days;
+use aoc2015::examples::{self, ExampleCheck};
+
+fn failures(include_slow: bool) -> Vec<ExampleCheck> {
+    days::solutions()
+        .iter()
+        .flat_map(|solution| examples::check(*solution, include_slow))
+        .filter(|check| !check.is_correct())
+        .collect()
+}
+
+#[test]
+fn declared_examples() {
+    let failures = failures(false);
+    assert!(failures.is_empty(), "{:#?}", failures);
+}
+
+// these are slow. ignore by default
+#[ignore]
+#[test]
+fn declared_slow_examples() {
+    let failures = failures(true);
+    assert!(failures.is_empty(), "{:#?}", failures);
+}
@@ -0,0 +1,20 @@
+use aoc2015::memory::{self, CountingAllocator};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn measures_allocations() {
+    memory::enable();
+
+    let (len, usage) = memory::measure(|| {
+        drop(vec![0u8; 1024]);
+        let held = vec![0u8; 4096];
+        held.len()
+    });
+    let usage = usage.unwrap();
+
+    assert_eq!(len, 4096);
+    assert!(usage.peak >= 4096 && usage.peak < 4096 + 1024);
+    assert!(usage.total >= 4096 + 1024);
+}
@@ -0,0 +1,40 @@
+use aoc2015::days;
+use serde_json::json;
+
+fn parsed_json(day: u8, data: &str) -> Option<serde_json::Value> {
+    days::find(day).unwrap().parsed_json(data).unwrap()
+}
+
+#[test]
+fn dumps_parsed_models() {
+    assert_eq!(parsed_json(2, "2x3x4\n"), Some(json!([[2, 3, 4]])));
+    assert_eq!(
+        parsed_json(6, "toggle 0,0 through 999,0"),
+        Some(json!([[
+            "toggle",
+            { "min": { "x": 0, "y": 0 }, "max": { "x": 999, "y": 0 } }
+        ]]))
+    );
+    assert_eq!(
+        parsed_json(7, "123 -> x\nx AND y -> d\nNOT x -> y"),
+        Some(json!({
+            "d": { "AND": ["x", "y"] },
+            "x": { "DIRECT": 123 },
+            "y": { "NOT": "x" },
+        }))
+    );
+    assert_eq!(
+        parsed_json(8, r#""a\x41""#),
+        Some(json!([{ "code": r#""a\x41""#, "parsed": "aA" }]))
+    );
+    assert_eq!(
+        parsed_json(9, "London to Dublin = 464"),
+        Some(json!([{ "from": "London", "to": "Dublin", "cost": 464 }]))
+    );
+}
+
+#[test]
+fn parses_days_without_a_model() {
+    assert_eq!(parsed_json(1, "(()"), None);
+    assert!(days::find(2).unwrap().parsed_json("2x3").is_err());
+}
@@ -0,0 +1,77 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::process::{Child, Command, Stdio};
+
+// kills the server however the test ends
+struct Server {
+    child: Child,
+    addr: String,
+}
+
+impl Server {
+    fn start() -> Self {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["serve", "--port", "0"])
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        let mut line = String::new();
+        BufReader::new(child.stdout.take().unwrap())
+            .read_line(&mut line)
+            .unwrap();
+        let addr = line
+            .trim()
+            .strip_prefix("listening on http://")
+            .unwrap()
+            .to_string();
+
+        Self { child, addr }
+    }
+
+    fn request(&self, method: &str, path: &str, body: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(&self.addr).unwrap();
+        write!(
+            stream,
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            method,
+            path,
+            body.len(),
+            body
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+        (status, body.to_string())
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn solves_posted_input() {
+    let server = Server::start();
+
+    let (status, body) = server.request("POST", "/day/2/part/1", "2x3x4");
+    assert_eq!(status, 200);
+    assert!(body.contains(r#""answer":58"#), "{}", body);
+
+    let (status, body) = server.request("POST", "/day/1/part/1", "(x");
+    assert_eq!(status, 400);
+    assert!(body.contains(r#""kind":"parse""#), "{}", body);
+
+    let (status, _) = server.request("POST", "/day/25/part/1", "");
+    assert_eq!(status, 404);
+
+    let (status, body) = server.request("GET", "/days", "");
+    assert_eq!(status, 200);
+    assert!(body.contains(r#""title":"Not Quite Lisp""#), "{}", body);
+}
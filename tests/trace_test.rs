@@ -0,0 +1,42 @@
+use std::fs;
+use std::process::Command;
+
+use aoc2015::cache;
+use serde_json::Value;
+
+#[test]
+fn writes_json_trace_file() {
+    let trace_file =
+        std::env::temp_dir().join(format!("aoc2015-trace-{}.json", std::process::id()));
+    let status = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        // a cached answer would skip the spans being checked
+        .env_remove(cache::DIR_VAR)
+        .args(["-d", "7", "-p", "1", "--trace-file"])
+        .arg(&trace_file)
+        .args(["-i", "123 -> b\nb AND 456 -> a\n"])
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.success());
+
+    let events: Vec<Value> = fs::read_to_string(&trace_file)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    fs::remove_file(&trace_file).unwrap();
+
+    let closed_spans: Vec<&Value> = events
+        .iter()
+        .filter(|e| e["fields"]["message"] == "close")
+        .map(|e| &e["span"]["name"])
+        .collect();
+    assert_eq!(closed_spans, ["parse", "solve"]);
+
+    let resolved: Vec<&Value> = events
+        .iter()
+        .filter(|e| e["fields"]["message"] == "resolved gate")
+        .map(|e| &e["fields"]["wire"])
+        .collect();
+    assert_eq!(resolved, ["b", "a"]);
+}
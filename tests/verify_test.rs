@@ -0,0 +1,22 @@
+use std::path::Path;
+
+use aoc2015::answers::{self, Answers};
+
+// data/ is not committed, so this only checks whatever inputs and answers
+// are present locally
+#[test]
+fn recorded_answers_match() {
+    let data_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+    let answers_file = data_dir.join(answers::ANSWERS_FILE);
+    if !answers_file.exists() {
+        return;
+    }
+
+    let answers = Answers::load(&answers_file).unwrap();
+    let failures: Vec<_> = answers::verify(&answers, &data_dir)
+        .into_iter()
+        .filter(|v| !v.is_correct())
+        .collect();
+
+    assert!(failures.is_empty(), "{:#?}", failures);
+}